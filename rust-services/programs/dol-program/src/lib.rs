@@ -4,14 +4,79 @@
 //! - Anyone can mint a free "Library Card" NFT to access the catalog
 //! - Admins curate books with metadata pointing to IPFS-stored content
 //! - All book metadata and user access records are stored on-chain
-//! - Future extensibility for collections, annotations, and community features
+//! - Card holders can group books into named collections and annotate what they read
+//! - Future extensibility for community features
+
+// Anchor 0.31's generated IDL handlers still call the deprecated `AccountInfo::realloc`
+#![allow(deprecated)]
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::program::MAX_RETURN_DATA;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
 
 declare_id!("DoLotrsAZR2JYa4tjue2c5q4EYKMbm6kxcrvjbU5cxX5");
 
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
+// Account space constants (shared by every context that creates the account)
+pub const DOL_STATE_SPACE: usize = ANCHOR_DISCRIMINATOR
+    + 32
+    + (4 + MAX_ADMINS * 32)
+    + (4 + MAX_MODERATORS * 32)
+    + (4 + MAX_CURATORS * 32)
+    + 8
+    + 1
+    + 1
+    + 1
+    + (1 + 32)
+    + 8
+    + 8
+    + 1
+    + 8
+    + (4 + MAX_ADMINS * 32)
+    + (1 + 32)
+    + 8
+    + 2
+    + 8
+    + 1
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 8
+    + 4
+    + 8
+    + 8
+    + 2
+    + 1;
+pub const LIBRARY_CARD_SPACE: usize =
+    ANCHOR_DISCRIMINATOR + 32 + 8 + 1 + 8 + 1 + 8 + 2 + 2 + 2 + 25;
+pub const BOOK_INDEX_SPACE: usize = ANCHOR_DISCRIMINATOR + (4 + MAX_CATALOG_BOOKS * 16) + 1;
+pub const TITLE_INDEX_SPACE: usize = ANCHOR_DISCRIMINATOR + 16 + 1;
+pub const CURATOR_STATS_SPACE: usize = ANCHOR_DISCRIMINATOR + 32 + 8 + 1 + 8 + 2 + 6;
+pub const COLLECTION_SPACE: usize = ANCHOR_DISCRIMINATOR
+    + 32
+    + 32
+    + (4 + MAX_COLLECTION_NAME_BYTES)
+    + (4 + MAX_COLLECTION_DESCRIPTION_BYTES)
+    + (4 + MAX_COLLECTION_BOOKS * 16)
+    + 8
+    + 1;
+pub const WISHLIST_SPACE: usize = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_WISHLIST_BOOKS * 16) + 1;
+pub const AUTHOR_INDEX_SPACE: usize =
+    ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_INDEX_BOOKS * 16) + 1;
+pub const GENRE_INDEX_SPACE: usize =
+    ANCHOR_DISCRIMINATOR + 1 + (4 + MAX_GENRE_INDEX_BOOKS * 16) + 1;
+pub const SEQ_INDEX_SPACE: usize = ANCHOR_DISCRIMINATOR + 8 + 16 + 1;
+pub const ANNOTATION_SPACE: usize =
+    ANCHOR_DISCRIMINATOR + 32 + 16 + 2 + 4 + 4 + (4 + MAX_ANNOTATION_NOTE_BYTES) + 8 + 8 + 1;
+
 // Role limits
 pub const MAX_ADMINS: usize = 3;
 pub const MAX_MODERATORS: usize = 5;
@@ -20,6 +85,137 @@ pub const MAX_CURATORS: usize = 10;
 // Rate limiting constants
 pub const MAX_BOOKS_PER_DAY: u16 = 50; // Maximum books that can be added per day
 pub const BOOK_ADDITION_COOLDOWN: i64 = 60; // Minimum seconds between book additions
+pub const MAX_CURATOR_ADDS_PER_WINDOW: u16 = 20; // Books one curator may add per window
+pub const CURATOR_RATE_WINDOW: i64 = 60 * 60; // Per-curator rate limit window (1 hour)
+
+// Transfer timelock bounds
+pub const MIN_TRANSFER_TIMELOCK: i64 = 60 * 60; // 1 hour
+pub const MAX_TRANSFER_TIMELOCK: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Emergency recovery timelock bounds
+pub const DEFAULT_RECOVERY_TIMELOCK: i64 = 48 * 60 * 60; // 48 hours
+pub const MAX_RECOVERY_TIMELOCK: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Super admin renunciation constants
+pub const RENOUNCE_CONFIRM_WINDOW: i64 = 10 * 60; // Seconds to repeat renounce_super_admin after arming it
+
+// Role permission bits (admins and the super admin always hold every permission)
+pub const CURATOR_CAN_ADD: u32 = 1 << 0; // add_book and propose_book
+pub const CURATOR_CAN_UPDATE: u32 = 1 << 1; // update_book
+pub const CURATOR_CAN_REMOVE: u32 = 1 << 2; // remove_book and remove_books_batch
+pub const MODERATOR_CAN_FLAG: u32 = 1 << 3; // flag_book
+pub const MODERATOR_CAN_RESOLVE_REPORTS: u32 = 1 << 4; // resolve_report
+pub const MODERATOR_CAN_DELETE_REVIEWS: u32 = 1 << 5; // delete_review on others' reviews
+pub const MODERATOR_CAN_REMOVE: u32 = 1 << 6; // remove_book and remove_books_batch
+pub const PERMISSION_MASK: u32 = (1 << 7) - 1; // Every defined permission bit
+pub const DEFAULT_PERMISSIONS: u32 = CURATOR_CAN_ADD
+    | CURATOR_CAN_UPDATE
+    | MODERATOR_CAN_FLAG
+    | MODERATOR_CAN_RESOLVE_REPORTS
+    | MODERATOR_CAN_DELETE_REVIEWS;
+
+// Pause constants
+pub const MAX_PAUSE_TOGGLE_COOLDOWN: i64 = 300; // Upper bound for the pause/unpause cooldown (5 minutes)
+pub const MAX_PAUSE_DURATION: i64 = 365 * 24 * 60 * 60; // Upper bound for a pause that lapses automatically (1 year)
+pub const PAUSE_FLAG_BOOKS: u8 = 1 << 1; // Freezes add_book and update_book
+pub const PAUSE_FLAG_CARDS: u8 = 1 << 2; // Freezes library card minting
+
+// Index constants
+pub const MAX_CATALOG_BOOKS: usize = 600; // Active books tracked by BookIndex (keeps it under the 10 KiB CPI allocation limit)
+pub const MAX_AUTHOR_BOOKS: usize = 50; // Most recent book IDs retained per authority (oldest evicted first)
+pub const MAX_AUTHOR_INDEX_BOOKS: usize = 100; // Book IDs per AuthorIndex (one account per primary author)
+pub const MAX_GENRE_INDEX_BOOKS: usize = 300; // Book IDs per GenreIndex (one account per genre)
+pub const MAX_PAGE_SIZE: u8 = 32; // Maximum IDs returned per page (return data is capped at 1024 bytes)
+
+// Rating constants
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+
+// Content rating constants
+pub const CONTENT_RATING_UNRATED: u8 = 0;
+pub const CONTENT_RATING_EVERYONE: u8 = 1;
+pub const CONTENT_RATING_TEEN: u8 = 2;
+pub const CONTENT_RATING_MATURE: u8 = 3;
+
+// Sponsored minting constants
+pub const MAX_SPONSORED_CARDS: usize = 10; // Maximum cards minted per sponsored batch (compute bound)
+
+// Batch removal constants
+pub const MAX_REMOVE_BATCH: usize = 5; // Maximum books closed per remove_books_batch (compute and transaction size bound)
+pub const REMOVE_BATCH_BOOK_ACCOUNTS: usize = 5; // Remaining accounts per book: book, title, author, genre, and seq indexes
+
+// Book length constants
+pub const MAX_PAGE_COUNT: u32 = 100_000;
+pub const MAX_WORD_COUNT: u32 = 50_000_000;
+pub const DEFAULT_READING_WPM: u16 = 238; // Average adult silent reading speed
+
+// Author constants
+pub const MAX_AUTHORS: usize = 3; // Co-authors stored per book (the first is the primary author)
+
+// Language constants
+pub const DEFAULT_LANGUAGE: [u8; 2] = *b"en"; // ISO 639-1 code used until a default is configured
+
+// Content identifier lengths
+pub const CIDV0_LEN: usize = 46; // "Qm" + base58btc sha2-256 multihash
+pub const CIDV1_MIN_LEN: usize = 59; // "b" + base32 of version, codec and sha2-256 multihash
+pub const CIDV1_MAX_LEN: usize = 110; // "b" + base32 of version, codec and sha2-512 multihash
+
+// String byte limits (UTF-8 text may use up to 4 bytes per character)
+// Character limits are what readers see; byte limits bound account space and transaction size
+pub const MAX_TITLE_BYTES: usize = 200;
+pub const MAX_AUTHOR_BYTES: usize = 100;
+pub const MAX_GENRE_LABEL_BYTES: usize = 60;
+
+// Description constants
+pub const MAX_DESCRIPTION_LEN: usize = 500; // Maximum characters in a book description
+pub const MAX_DESCRIPTION_BYTES: usize = 1000;
+
+// Review constants
+pub const MAX_REVIEW_TEXT_LEN: usize = 280; // Maximum characters in a written review
+pub const MAX_REVIEW_TEXT_BYTES: usize = 560; // Stored bytes, the review account is sized for this
+
+// Collection constants
+pub const MAX_COLLECTION_BOOKS: usize = 32; // Book IDs one collection can hold
+pub const MAX_COLLECTION_NAME_LEN: usize = 50; // Maximum characters in a collection name
+pub const MAX_COLLECTION_NAME_BYTES: usize = 100;
+pub const MAX_COLLECTION_DESCRIPTION_LEN: usize = 200; // Maximum characters in a collection description
+pub const MAX_COLLECTION_DESCRIPTION_BYTES: usize = 400;
+
+// Wishlist constants
+pub const MAX_WISHLIST_BOOKS: usize = 50; // Book IDs one member can wishlist
+
+// Annotation constants
+pub const MAX_ANNOTATIONS_PER_BOOK: u16 = 64; // Annotation slots per reader per book (index 0..64)
+pub const MAX_ANNOTATION_NOTE_LEN: usize = 140; // Maximum characters in an annotation note
+pub const MAX_ANNOTATION_NOTE_BYTES: usize = 280;
+
+// Book status flags
+pub const BOOK_FLAG_ARCHIVED: u8 = 1; // Hidden from the catalog, record kept for provenance
+pub const BOOK_FLAG_FLAGGED: u8 = 2; // Flagged by a moderator after a community report
+pub const BOOK_FLAG_UNDER_REVIEW: u8 = 4; // Hidden from readers while a moderator reviews it
+
+// Report constants
+pub const REPORT_REASON_SPAM: u8 = 0;
+pub const REPORT_REASON_COPYRIGHT: u8 = 1;
+pub const REPORT_REASON_INAPPROPRIATE: u8 = 2;
+pub const REPORT_REASON_INACCURATE_METADATA: u8 = 3;
+pub const REPORT_REASON_OTHER: u8 = 4;
+pub const MAX_REPORT_DETAILS_LEN: usize = 200; // Maximum characters in a report's details
+pub const MAX_REPORT_DETAILS_BYTES: usize = 400;
+pub const REPORT_ACTION_DISMISS: u8 = 0; // Close the report, leave the book untouched
+pub const REPORT_ACTION_FLAG: u8 = 1; // Close the report and flag the book
+
+// Role change constants
+pub const MAX_ROLE_NOTE_LEN: usize = 64; // Optional governance note attached to role changes
+pub const MAX_ROLE_NOTE_BYTES: usize = 128;
+
+// Moderation constants
+pub const MAX_BOOK_VISIBILITY_DELAY: i64 = 7 * 24 * 60 * 60; // Upper bound for the new-book grace window (7 days)
+pub const MAX_MIN_CARD_AGE: i64 = 30 * 24 * 60 * 60; // Upper bound for the card age community features require (30 days)
+pub const CARD_TRANSFER_COOLDOWN: i64 = 7 * 24 * 60 * 60; // Minimum age a transferred card waits out on its new wallet
+
+// Read tracking constants
+pub const READ_COUNT_COOLDOWN: i64 = 24 * 60 * 60; // Minimum seconds before a repeat read counts again
 
 // Role checking helper functions
 impl DoLState {
@@ -43,8 +239,39 @@ impl DoLState {
         self.is_super_admin(user) || self.is_admin(user)
     }
 
+    pub fn has_permission(&self, permission: u32) -> bool {
+        self.permissions & permission != 0
+    }
+
     pub fn can_add_books(&self, user: &Pubkey) -> bool {
-        self.is_super_admin(user) || self.is_admin(user) || self.is_curator(user)
+        self.has_admin_privileges(user)
+            || (self.is_curator(user) && self.has_permission(CURATOR_CAN_ADD))
+    }
+
+    pub fn can_update_books(&self, user: &Pubkey) -> bool {
+        self.has_admin_privileges(user)
+            || (self.is_curator(user) && self.has_permission(CURATOR_CAN_UPDATE))
+    }
+
+    pub fn can_remove_books(&self, user: &Pubkey) -> bool {
+        self.has_admin_privileges(user)
+            || (self.is_curator(user) && self.has_permission(CURATOR_CAN_REMOVE))
+            || (self.is_moderator(user) && self.has_permission(MODERATOR_CAN_REMOVE))
+    }
+
+    pub fn can_flag_books(&self, user: &Pubkey) -> bool {
+        self.has_admin_privileges(user)
+            || (self.is_moderator(user) && self.has_permission(MODERATOR_CAN_FLAG))
+    }
+
+    pub fn can_resolve_reports(&self, user: &Pubkey) -> bool {
+        self.has_admin_privileges(user)
+            || (self.is_moderator(user) && self.has_permission(MODERATOR_CAN_RESOLVE_REPORTS))
+    }
+
+    pub fn can_delete_reviews(&self, user: &Pubkey) -> bool {
+        self.has_admin_privileges(user)
+            || (self.is_moderator(user) && self.has_permission(MODERATOR_CAN_DELETE_REVIEWS))
     }
 
     pub fn can_manage_roles(&self, user: &Pubkey) -> bool {
@@ -52,7 +279,31 @@ impl DoLState {
     }
 
     pub fn is_paused(&self) -> bool {
-        self.flags & 1 != 0
+        if self.flags & 1 == 0 {
+            return false;
+        }
+        // A pause lapses once max_pause_duration has elapsed since it started
+        if self.max_pause_duration > 0 {
+            if let Ok(clock) = Clock::get() {
+                return clock.unix_timestamp
+                    < self
+                        .last_pause_change_at
+                        .saturating_add(self.max_pause_duration);
+            }
+        }
+        true
+    }
+
+    pub fn is_books_paused(&self) -> bool {
+        self.is_paused() || self.flags & PAUSE_FLAG_BOOKS != 0
+    }
+
+    pub fn is_cards_paused(&self) -> bool {
+        self.is_paused() || self.flags & PAUSE_FLAG_CARDS != 0
+    }
+
+    pub fn is_catalog_full(&self) -> bool {
+        self.max_books != 0 && self.book_count >= self.max_books
     }
 
     pub fn set_paused(&mut self, paused: bool) {
@@ -63,6 +314,15 @@ impl DoLState {
         }
     }
 
+    /// Advance the membership counter and return the number for a new card
+    pub fn next_membership_number(&mut self) -> Result<u64> {
+        self.membership_number = self
+            .membership_number
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+        Ok(self.membership_number)
+    }
+
     pub fn has_pending_transfer(&self) -> bool {
         self.pending_super_admin.is_some()
     }
@@ -76,6 +336,19 @@ impl DoLState {
         )
     }
 
+    /// Language for a new book; None or [0, 0] takes the configured default
+    pub fn language_or_default(&self, language: Option<[u8; 2]>) -> [u8; 2] {
+        match language {
+            Some(code) if code != [0; 2] => code,
+            _ if self.default_language == [0; 2] => DEFAULT_LANGUAGE,
+            _ => self.default_language,
+        }
+    }
+
+    pub fn is_recovery_feasible(&self) -> bool {
+        self.admins.len() >= self.emergency_recovery_threshold as usize
+    }
+
     pub fn get_emergency_recovery_status(&self) -> (bool, Option<Pubkey>, Vec<Pubkey>, u8) {
         (
             self.emergency_recovery_new_admin.is_some(),
@@ -86,60 +359,309 @@ impl DoLState {
     }
 }
 
+impl Book {
+    pub fn is_archived(&self) -> bool {
+        self.flags & BOOK_FLAG_ARCHIVED != 0
+    }
+
+    pub fn is_flagged(&self) -> bool {
+        self.flags & BOOK_FLAG_FLAGGED != 0
+    }
+
+    pub fn is_under_review(&self) -> bool {
+        self.flags & BOOK_FLAG_UNDER_REVIEW != 0
+    }
+
+    /// ISO 639-1 language code; books stored before languages existed read as the default
+    pub fn language_code(&self) -> [u8; 2] {
+        if self.language == [0; 2] {
+            DEFAULT_LANGUAGE
+        } else {
+            self.language
+        }
+    }
+
+    /// Estimated minutes to read at `wpm` words per minute, if the word count is known
+    pub fn est_reading_minutes(&self, wpm: u16) -> Option<u32> {
+        if self.word_count == 0 || wpm == 0 {
+            return None;
+        }
+        Some(self.word_count.div_ceil(wpm as u32))
+    }
+}
+
+impl Genre {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Genre::Fiction => "Fiction",
+            Genre::NonFiction => "Non-Fiction",
+            Genre::Science => "Science",
+            Genre::History => "History",
+            Genre::Biography => "Biography",
+            Genre::Poetry => "Poetry",
+            Genre::Children => "Children",
+            Genre::Reference => "Reference",
+            Genre::Other => "Other",
+        }
+    }
+}
+
+impl CardTier {
+    pub fn from_u8(tier: u8) -> Result<CardTier> {
+        match tier {
+            0 => Ok(CardTier::Standard),
+            1 => Ok(CardTier::Premium),
+            _ => err!(DoLError::InvalidCardTier),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardTier::Standard => "Standard",
+            CardTier::Premium => "Premium",
+        }
+    }
+}
+
 // Enhanced validation helpers
+/// String fields checked by `validate_string_input`, each mapped to its length error
+#[derive(Clone, Copy)]
+enum StringField {
+    Title,
+    Author,
+    Genre,
+    Note,
+    Review,
+    Report,
+    CollectionName,
+    CollectionDescription,
+    Annotation,
+    Description,
+}
+
+impl StringField {
+    fn length_error(self) -> DoLError {
+        match self {
+            StringField::Title => DoLError::TitleTooLong,
+            StringField::Author => DoLError::AuthorTooLong,
+            StringField::Genre => DoLError::GenreTooLong,
+            StringField::Note => DoLError::InvalidLength,
+            StringField::Review => DoLError::InvalidLength,
+            StringField::Report => DoLError::InvalidLength,
+            StringField::CollectionName => DoLError::InvalidLength,
+            StringField::CollectionDescription => DoLError::InvalidLength,
+            StringField::Annotation => DoLError::InvalidLength,
+            StringField::Description => DoLError::DescriptionTooLong,
+        }
+    }
+
+    fn max_bytes(self) -> usize {
+        match self {
+            StringField::Title => MAX_TITLE_BYTES,
+            StringField::Author => MAX_AUTHOR_BYTES,
+            StringField::Genre => MAX_GENRE_LABEL_BYTES,
+            StringField::Note => MAX_ROLE_NOTE_BYTES,
+            StringField::Review => MAX_REVIEW_TEXT_BYTES,
+            StringField::Report => MAX_REPORT_DETAILS_BYTES,
+            StringField::CollectionName => MAX_COLLECTION_NAME_BYTES,
+            StringField::CollectionDescription => MAX_COLLECTION_DESCRIPTION_BYTES,
+            StringField::Annotation => MAX_ANNOTATION_NOTE_BYTES,
+            StringField::Description => MAX_DESCRIPTION_BYTES,
+        }
+    }
+}
+
+/// Check a user-supplied string against its field's limits
+/// `min_len`/`max_len` count Unicode scalar values (`chars()`), not grapheme clusters, so an
+/// emoji is one character and a combining accent is another on top of its base letter. The
+/// UTF-8 length is also capped at `field.max_bytes()`, which is what the account space is sized for.
 fn validate_string_input(
     input: &str,
     min_len: usize,
     max_len: usize,
-    field_name: &str,
+    field: StringField,
 ) -> Result<()> {
-    // Check length
+    // Check character count first, then the stored byte length
+    let char_count: usize = input.chars().count();
     require!(
-        input.len() >= min_len && input.len() <= max_len,
-        match field_name {
-            "title" => DoLError::TitleTooLong,
-            "author" => DoLError::AuthorTooLong,
-            "genre" => DoLError::GenreTooLong,
-            _ => DoLError::InvalidBookId,
-        }
+        char_count >= min_len && char_count <= max_len && input.len() <= field.max_bytes(),
+        field.length_error()
     );
 
-    // Check for non-printable characters
+    // Then check for control characters (any other UTF-8 text is allowed)
     require!(
-        input.chars().all(|c| c.is_ascii_graphic() || c == ' '),
-        DoLError::InvalidInput
+        !input.chars().any(char::is_control),
+        DoLError::InvalidCharacters
     );
 
+    // Finally apply content policy: no whitespace-only values
+    require!(!input.trim().is_empty(), DoLError::InvalidInput);
+
     Ok(())
 }
 
-fn validate_ipfs_hash_enhanced(hash: &str) -> Result<()> {
-    // Basic IPFS validation
+fn validate_role_note(note: &str) -> Result<()> {
+    // Notes are optional; only non-empty notes go through the string checks
+    if !note.is_empty() {
+        validate_string_input(note, 1, MAX_ROLE_NOTE_LEN, StringField::Note)?;
+    }
+
+    Ok(())
+}
+
+fn validate_description(description: &str) -> Result<()> {
+    // Descriptions are optional; only non-empty ones go through the string checks
+    if !description.is_empty() {
+        validate_string_input(
+            description,
+            1,
+            MAX_DESCRIPTION_LEN,
+            StringField::Description,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn validate_authors(authors: &[String]) -> Result<()> {
     require!(
-        hash.len() >= 32 && (hash.starts_with("Qm") || hash.starts_with("baf")),
-        DoLError::InvalidIpfsHash
+        !authors.is_empty() && authors.len() <= MAX_AUTHORS,
+        DoLError::TooManyAuthors
     );
+    for author in authors {
+        validate_string_input(author, 1, 50, StringField::Author)?;
+    }
+
+    Ok(())
+}
+
+fn validate_annotation(start_offset: u32, end_offset: u32, note: &str) -> Result<()> {
+    // Ranges are half-open byte offsets into the book content
+    require!(start_offset < end_offset, DoLError::InvalidAnnotationRange);
+    // Notes are optional; a bare range is a highlight
+    if !note.is_empty() {
+        validate_string_input(note, 1, MAX_ANNOTATION_NOTE_LEN, StringField::Annotation)?;
+    }
+
+    Ok(())
+}
 
-    // Check for valid base58 characters (for Qm hashes) or base32 (for baf hashes)
+fn validate_ipfs_hash_enhanced(hash: &str) -> Result<()> {
     if hash.starts_with("Qm") {
-        // Base58 alphabet used by IPFS (Bitcoin alphabet without 0, O, I, l)
+        // CIDv0 is always a base58btc sha2-256 multihash: "Qm" + 44 chars
         const BASE58_CHARS: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        require!(hash.len() == CIDV0_LEN, DoLError::InvalidIpfsHash);
         require!(
             hash.chars().all(|c| BASE58_CHARS.contains(c)),
             DoLError::InvalidIpfsHash
         );
-    } else if hash.starts_with("baf") {
-        // Proper base32 alphabet (RFC 4648) - lowercase
+    } else if hash.starts_with("bafy") || hash.starts_with("bafk") {
+        // CIDv1 in multibase base32 ("b" prefix) with the dag-pb ("bafy")
+        // or raw ("bafk") codec, from a sha2-256 up to a sha2-512 digest
         const BASE32_CHARS: &str = "abcdefghijklmnopqrstuvwxyz234567";
         require!(
-            hash.chars().skip(3).all(|c| BASE32_CHARS.contains(c)),
+            (CIDV1_MIN_LEN..=CIDV1_MAX_LEN).contains(&hash.len()),
+            DoLError::InvalidIpfsHash
+        );
+        require!(
+            hash.chars().skip(1).all(|c| BASE32_CHARS.contains(c)),
             DoLError::InvalidIpfsHash
         );
+    } else {
+        return err!(DoLError::InvalidIpfsHash);
+    }
+
+    Ok(())
+}
+
+fn validate_content_rating(content_rating: u8) -> Result<()> {
+    require!(
+        content_rating <= CONTENT_RATING_MATURE,
+        DoLError::InvalidContentRating
+    );
+
+    Ok(())
+}
+
+fn validate_book_length(page_count: u32, word_count: u32) -> Result<()> {
+    // Zero means unknown for both
+    require!(page_count <= MAX_PAGE_COUNT, DoLError::InvalidPageCount);
+    require!(word_count <= MAX_WORD_COUNT, DoLError::InvalidWordCount);
+
+    Ok(())
+}
+
+fn validate_genre(genre: Genre, genre_label: &str) -> Result<()> {
+    // Only the Other variant carries a free-form label
+    if genre == Genre::Other {
+        validate_string_input(genre_label, 1, 30, StringField::Genre)?;
+    } else {
+        require!(genre_label.is_empty(), DoLError::GenreLabelNotAllowed);
     }
 
     Ok(())
 }
 
+fn validate_isbn(isbn: &[u8; 13]) -> Result<()> {
+    // ISBN-13: ASCII digits weighted 1, 3, 1, 3, ... must sum to a multiple of 10
+    require!(
+        isbn.iter().all(|b| b.is_ascii_digit()),
+        DoLError::InvalidIsbn
+    );
+    let sum: u32 = isbn
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| u32::from(b - b'0') * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    require!(sum.is_multiple_of(10), DoLError::InvalidIsbn);
+
+    Ok(())
+}
+
+fn validate_lccn(lccn: &[u8; 12]) -> Result<()> {
+    // Printable characters, right-padded with zeros (all zeros = none)
+    let len: usize = lccn.iter().position(|&b| b == 0).unwrap_or(lccn.len());
+    require!(
+        lccn[..len].iter().all(|b| b.is_ascii_graphic()) && lccn[len..].iter().all(|&b| b == 0),
+        DoLError::InvalidLccn
+    );
+
+    Ok(())
+}
+
+/// Gregorian calendar year containing `unix_timestamp` (UTC)
+fn year_from_timestamp(unix_timestamp: i64) -> i64 {
+    // Civil-from-days conversion over 400-year eras
+    let z: i64 = unix_timestamp.div_euclid(86400) + 719_468;
+    let era: i64 = z.div_euclid(146_097);
+    let doe: i64 = z - era * 146_097;
+    let yoe: i64 = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: i64 = (5 * doy + 2) / 153;
+    let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+    yoe + era * 400 + i64::from(month <= 2)
+}
+
+fn validate_publication_year(year: u16, current_timestamp: i64) -> Result<()> {
+    // Known years must be positive and not in the future
+    require!(
+        year >= 1 && i64::from(year) <= year_from_timestamp(current_timestamp),
+        DoLError::InvalidPublicationYear
+    );
+
+    Ok(())
+}
+
+fn validate_language(language: &[u8; 2]) -> Result<()> {
+    // ISO 639-1: exactly two lowercase ASCII letters
+    require!(
+        language.iter().all(|b| b.is_ascii_lowercase()),
+        DoLError::InvalidLanguage
+    );
+
+    Ok(())
+}
+
 fn validate_uuid_v4(uuid: &[u8; 16]) -> Result<()> {
     // Check that UUID is not all zeros
     require!(uuid != &[0; 16], DoLError::InvalidBookId);
@@ -159,6 +681,99 @@ fn validate_uuid_v4(uuid: &[u8; 16]) -> Result<()> {
     Ok(())
 }
 
+fn validate_pause_toggle_cooldown(dol_state: &DoLState, current_time: i64) -> Result<()> {
+    // A zero cooldown or a never-toggled flag imposes no restriction
+    if dol_state.pause_toggle_cooldown > 0 && dol_state.last_pause_change_at > 0 {
+        require!(
+            current_time - dol_state.last_pause_change_at >= dol_state.pause_toggle_cooldown,
+            DoLError::PauseToggleCooldown
+        );
+    }
+
+    Ok(())
+}
+
+fn validate_book_visible(
+    dol_state: &DoLState,
+    book: &Book,
+    reader: &Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    // Moderators (and higher roles) can still reach archived, not-yet-visible, and under-review books
+    if dol_state.is_moderator(reader) || dol_state.has_admin_privileges(reader) {
+        return Ok(());
+    }
+
+    require!(!book.is_archived(), DoLError::BookArchived);
+
+    require!(!book.is_under_review(), DoLError::BookUnderReview);
+
+    require!(
+        current_time >= book.added_timestamp + dol_state.book_visibility_delay,
+        DoLError::BookNotYetVisible
+    );
+
+    Ok(())
+}
+
+fn validate_distinct_cover(
+    dol_state: &DoLState,
+    ipfs_hash: &str,
+    cover_ipfs_hash: &str,
+) -> Result<()> {
+    // A cover equal to the content is usually a client bug; deployments can opt into rejecting it
+    require!(
+        !dol_state.distinct_cover || cover_ipfs_hash.is_empty() || cover_ipfs_hash != ipfs_hash,
+        DoLError::CoverMatchesContent
+    );
+
+    Ok(())
+}
+
+/// Whether a card moved wallets too recently to act as an established card
+/// Rating, review, report and read receipts are keyed by wallet, so a transfer restarts the
+/// card's age and the new wallet waits at least CARD_TRANSFER_COOLDOWN
+fn is_recently_transferred(dol_state: &DoLState, card: &LibraryCard, current_time: i64) -> bool {
+    let wait: i64 = dol_state.min_card_age_seconds.max(CARD_TRANSFER_COOLDOWN);
+    card.transferred_at != 0 && current_time < card.transferred_at.saturating_add(wait)
+}
+
+fn validate_card_age(dol_state: &DoLState, card: &LibraryCard, current_time: i64) -> Result<()> {
+    // Fresh cards wait out the minimum age before community features open up
+    require!(
+        current_time >= card.mint_timestamp + dol_state.min_card_age_seconds,
+        DoLError::CardTooNew
+    );
+    // A transferred card counts as new on its new wallet
+    require!(
+        !is_recently_transferred(dol_state, card, current_time),
+        DoLError::CardTooNew
+    );
+
+    Ok(())
+}
+
+fn validate_role_address(dol_state: &DoLState, member: &Pubkey, role: RoleKind) -> Result<()> {
+    // A zero key can never sign, so it would only burn a role slot
+    require!(*member != Pubkey::default(), DoLError::InvalidRoleAddress);
+
+    // Admins already hold every curator power, so the two roles stay disjoint;
+    // moderators may overlap with either
+    match role {
+        RoleKind::Admin => require!(
+            !dol_state.is_super_admin(member) && !dol_state.is_curator(member),
+            DoLError::RoleConflict
+        ),
+        RoleKind::Curator => require!(
+            !dol_state.has_admin_privileges(member),
+            DoLError::RoleConflict
+        ),
+        RoleKind::Moderator => {}
+    }
+
+    Ok(())
+}
+
 fn validate_super_admin_address(
     new_super_admin: &Pubkey,
     current_super_admin: &Pubkey,
@@ -192,869 +807,6804 @@ fn validate_super_admin_address(
     Ok(())
 }
 
-#[program]
-pub mod dol_program {
-    use super::*;
+// Account space helpers
+/// Space for a Book account holding the given variable-length fields
+fn book_space(
+    title: &str,
+    authors: &[String],
+    ipfs_hash: &str,
+    metadata_ipfs_hash: &str,
+    cover_ipfs_hash: &str,
+    genre_label: &str,
+    description: &str,
+) -> usize {
+    ANCHOR_DISCRIMINATOR
+        + 16
+        + (4 + title.len())
+        + (4 + authors.iter().map(|author| 4 + author.len()).sum::<usize>())
+        + (4 + ipfs_hash.len())
+        + (4 + metadata_ipfs_hash.len())
+        + (4 + cover_ipfs_hash.len())
+        + 1
+        + (4 + genre_label.len())
+        + 1
+        + 4
+        + 4
+        + 8
+        + 12
+        + 2
+        + 8
+        + 32
+        + 1
+        + 8
+        + 8
+        + 4
+        + (5 * 4)
+        + 4
+        + 4
+        + 16
+        + 32
+        + 8
+        + 1
+        + 2
+        + (1 + 13)
+        + (4 + description.len())
+        + 8
+        + 32
+        + 1
+}
 
-    /// Initialize the DoL program with the initializing authority as super admin
-    /// This sets up the global state with role-based access control
-    /// Note: In production, use a multi-sig wallet address as the initializer
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        // The initializer becomes the super admin
-        // SECURITY: Ensure this is called with a multi-sig wallet in production
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        dol_state.super_admin = ctx.accounts.super_admin.key();
-        dol_state.admins = Vec::new();
-        dol_state.moderators = Vec::new();
-        dol_state.curators = Vec::new();
-        dol_state.book_count = 0;
-        dol_state.version = 1;
-        dol_state.flags = 0; // Start unpaused (bit 0 = 0)
-        dol_state.bump = ctx.bumps.dol_state;
-        // Initialize secure transfer fields
-        dol_state.pending_super_admin = None;
-        dol_state.transfer_initiated_at = 0;
-        dol_state.transfer_timelock = 7 * 24 * 60 * 60; // 7 days in seconds
-                                                        // Initialize emergency recovery fields
-        dol_state.emergency_recovery_threshold = 2; // Require 2 admin signatures minimum
-        dol_state.emergency_recovery_initiated_at = 0;
-        dol_state.emergency_recovery_votes = Vec::new();
-        dol_state.emergency_recovery_new_admin = None;
-        // Initialize rate limiting fields
-        dol_state.last_book_addition = 0;
-        dol_state.books_added_today = 0;
-        dol_state.last_book_addition_day = 0;
+// Catalog index helpers
+/// Dedup key for a title and author, case-insensitive
+/// The NUL separator can't occur in validated text, so ("ab", "c") and ("a", "bc") differ
+fn title_index_key(title: &str, author: &str) -> [u8; 32] {
+    let normalized: String = format!("{}\0{}", title.to_lowercase(), author.to_lowercase());
+    hash(normalized.as_bytes()).to_bytes()
+}
 
-        msg!(
-            "DoL program initialized with super admin: {:?}",
-            dol_state.super_admin
-        );
-        Ok(())
+/// Add a book to the catalog-wide index, creating the index on first use
+fn index_book(book_index: &mut BookIndex, bump: u8, book_id: [u8; 16]) -> Result<()> {
+    book_index.bump = bump;
+    require!(
+        book_index.book_ids.len() < MAX_CATALOG_BOOKS,
+        DoLError::CatalogFull
+    );
+    book_index.book_ids.push(book_id);
+    Ok(())
+}
+
+/// Create a program-owned PDA, taking over an address someone pre-funded
+fn create_program_pda<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    let rent_exempt: u64 = Rent::get()?.minimum_balance(space);
+    let current_lamports: u64 = account.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent_exempt,
+            space as u64,
+            program_id,
+        )?;
+    } else {
+        // Someone pre-funded the address; top it up and take ownership instead
+        if current_lamports < rent_exempt {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_exempt - current_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign {
+                    account_to_assign: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            program_id,
+        )?;
     }
+    Ok(())
+}
 
-    /// Mint a free Library Card NFT that grants access to read all books
-    /// Each user can only have one card (enforced by PDA seeds)
-    pub fn mint_library_card(ctx: Context<MintLibraryCard>) -> Result<()> {
-        // Create the library card
-        let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
-        library_card.owner = ctx.accounts.user.key();
-        library_card.mint_timestamp = Clock::get()?.unix_timestamp;
-        library_card.bump = ctx.bumps.library_card;
+/// Index key for a primary author, case-insensitive
+fn author_index_key(author: &str) -> [u8; 32] {
+    hash(author.to_lowercase().as_bytes()).to_bytes()
+}
 
-        msg!("Library card minted for: {:?}", library_card.owner);
-        Ok(())
-    }
+/// Append a book to its primary author's index, creating the index on first use
+fn index_book_by_author<'info>(
+    author_index: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    author: &str,
+    book_id: [u8; 16],
+) -> Result<()> {
+    let key: [u8; 32] = author_index_key(author);
+    let (expected_index, bump) = Pubkey::find_program_address(&[b"author_idx", &key], program_id);
+    require_keys_eq!(author_index.key(), expected_index, DoLError::IndexMismatch);
+
+    let mut index: AuthorIndex = if author_index.owner == program_id {
+        AuthorIndex::try_deserialize(&mut &author_index.try_borrow_data()?[..])?
+    } else {
+        create_program_pda(
+            author_index,
+            payer,
+            system_program,
+            program_id,
+            &[b"author_idx", &key, &[bump]],
+            AUTHOR_INDEX_SPACE,
+        )?;
+        AuthorIndex {
+            author_key: key,
+            book_ids: Vec::new(),
+            bump,
+        }
+    };
+    require!(
+        index.book_ids.len() < MAX_AUTHOR_INDEX_BOOKS,
+        DoLError::IndexFull
+    );
+    index.book_ids.push(book_id);
 
-    /// Add a new book to the catalog (super admin, admin, or curator)
-    /// Books are stored with metadata pointing to IPFS content
-    /// The client must provide a unique UUID for the book ID
-    pub fn add_book(
-        ctx: Context<AddBook>,
-        id: [u8; 16],
-        title: String,
-        author: String,
-        ipfs_hash: String,
-        genre: String,
-    ) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
+    let mut data = author_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+    Ok(())
+}
 
-        // Check if program is paused
-        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+/// Append a book to its genre's index, creating the index on first use
+fn index_book_by_genre<'info>(
+    genre_index: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    genre: Genre,
+    book_id: [u8; 16],
+) -> Result<()> {
+    let (expected_index, bump) =
+        Pubkey::find_program_address(&[b"genre_idx", &[genre as u8]], program_id);
+    require_keys_eq!(genre_index.key(), expected_index, DoLError::IndexMismatch);
+
+    let mut index: GenreIndex = if genre_index.owner == program_id {
+        GenreIndex::try_deserialize(&mut &genre_index.try_borrow_data()?[..])?
+    } else {
+        create_program_pda(
+            genre_index,
+            payer,
+            system_program,
+            program_id,
+            &[b"genre_idx", &[genre as u8], &[bump]],
+            GENRE_INDEX_SPACE,
+        )?;
+        GenreIndex {
+            genre,
+            book_ids: Vec::new(),
+            bump,
+        }
+    };
+    require!(
+        index.book_ids.len() < MAX_GENRE_INDEX_BOOKS,
+        DoLError::IndexFull
+    );
+    index.book_ids.push(book_id);
 
-        // Check if user has permission to add books
-        require!(
-            dol_state.can_add_books(signer),
-            DoLError::InsufficientPermissions
-        );
+    let mut data = genre_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+    Ok(())
+}
 
-        // Rate limiting checks
-        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
-        let current_day: i64 = current_timestamp / 86400; // Convert to day number
+/// Edit a wallet's library card, if it still holds one (cards can move wallets)
+fn edit_library_card(
+    library_card: &AccountInfo<'_>,
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    edit: impl FnOnce(&mut LibraryCard),
+) -> Result<()> {
+    let (expected_card, _) =
+        Pubkey::find_program_address(&[b"library_card", owner.as_ref()], program_id);
+    require_keys_eq!(library_card.key(), expected_card, DoLError::IndexMismatch);
+    if library_card.owner != program_id {
+        return Ok(());
+    }
 
-        // Check cooldown period
-        if dol_state.last_book_addition > 0 {
-            let time_since_last_addition: i64 = current_timestamp - dol_state.last_book_addition;
-            require!(
-                time_since_last_addition >= BOOK_ADDITION_COOLDOWN,
-                DoLError::RateLimitExceeded
-            );
-        }
+    let mut card: LibraryCard =
+        LibraryCard::try_deserialize(&mut &library_card.try_borrow_data()?[..])?;
+    edit(&mut card);
 
-        // Reset daily counter if it's a new day
-        if current_day != dol_state.last_book_addition_day {
-            dol_state.books_added_today = 0;
-            dol_state.last_book_addition_day = current_day;
-        }
+    let mut data = library_card.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    card.try_serialize(&mut writer)?;
+    Ok(())
+}
 
-        // Check daily limit
-        require!(
-            dol_state.books_added_today < MAX_BOOKS_PER_DAY,
-            DoLError::DailyLimitExceeded
-        );
+/// Edit the book IDs in a primary author's index (books added before the index have none)
+fn edit_author_index(
+    author_index: &AccountInfo<'_>,
+    program_id: &Pubkey,
+    author: &str,
+    edit: impl FnOnce(&mut Vec<[u8; 16]>),
+) -> Result<()> {
+    let key: [u8; 32] = author_index_key(author);
+    let (expected_index, _) = Pubkey::find_program_address(&[b"author_idx", &key], program_id);
+    require_keys_eq!(author_index.key(), expected_index, DoLError::IndexMismatch);
+    if author_index.owner != program_id {
+        return Ok(());
+    }
 
-        // Validate UUID v4 format
-        validate_uuid_v4(&id)?;
+    let mut index: AuthorIndex =
+        AuthorIndex::try_deserialize(&mut &author_index.try_borrow_data()?[..])?;
+    edit(&mut index.book_ids);
 
-        // Enhanced validation for all input fields
-        validate_string_input(&title, 1, 100, "title")?;
-        validate_string_input(&author, 1, 50, "author")?;
-        validate_string_input(&genre, 1, 30, "genre")?;
-        validate_ipfs_hash_enhanced(&ipfs_hash)?;
+    let mut data = author_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+    Ok(())
+}
 
-        // Get the book account
-        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+/// Edit the book IDs in a genre's index (books added before the index have none)
+fn edit_genre_index(
+    genre_index: &AccountInfo<'_>,
+    program_id: &Pubkey,
+    genre: Genre,
+    edit: impl FnOnce(&mut Vec<[u8; 16]>),
+) -> Result<()> {
+    let (expected_index, _) =
+        Pubkey::find_program_address(&[b"genre_idx", &[genre as u8]], program_id);
+    require_keys_eq!(genre_index.key(), expected_index, DoLError::IndexMismatch);
+    if genre_index.owner != program_id {
+        return Ok(());
+    }
 
-        // Store book metadata with client-provided UUID
-        book.id = id;
-        book.title = title;
-        book.author = author;
-        book.ipfs_hash = ipfs_hash;
-        book.genre = genre;
-        book.publication_year = 0; // Optional field for future use
-        book.added_timestamp = Clock::get()?.unix_timestamp;
-        book.added_by = ctx.accounts.authority.key(); // Record who added the book
-        book.bump = ctx.bumps.book;
+    let mut index: GenreIndex =
+        GenreIndex::try_deserialize(&mut &genre_index.try_borrow_data()?[..])?;
+    edit(&mut index.book_ids);
 
-        // Increment counter for analytics
-        dol_state.book_count += 1;
+    let mut data = genre_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+    Ok(())
+}
 
-        // Update rate limiting fields
-        dol_state.last_book_addition = current_timestamp;
-        dol_state.books_added_today += 1;
+/// Release the indexes of a book closed by a batch removal, as remove_book does
+/// `indexes` are the book's title, author, genre, and sequential index accounts
+fn release_batch_indexes<'info>(
+    book: &Book,
+    indexes: &'info [AccountInfo<'info>],
+    authority: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    // Only books added before the title index may pass the program ID in its place
+    if book.title_key != [0; 32] || indexes[0].key() != *program_id {
+        let title_index: Account<'info, BookTitleIndex> =
+            Account::try_from(&indexes[0]).map_err(|_| DoLError::InvalidBatchAccounts)?;
+        require!(title_index.book_id == book.id, DoLError::TitleIndexMismatch);
+        title_index.close(authority.clone())?;
+    }
 
-        msg!(
-            "Book added: {} by {} (ID: {:?}) by {:?}",
-            book.title,
-            book.author,
-            &id[..4],
-            signer
-        );
-        Ok(())
+    edit_author_index(&indexes[1], program_id, &book.authors[0], |book_ids| {
+        book_ids.retain(|book_id| *book_id != book.id)
+    })?;
+    edit_genre_index(&indexes[2], program_id, book.genre, |book_ids| {
+        book_ids.retain(|book_id| *book_id != book.id)
+    })?;
+
+    release_seq_index(&indexes[3], book, authority, program_id)
+}
+
+/// Free a removed book's sequence slot, refunding its rent to `destination`
+/// A book added before the sequential index doesn't own its slot, so it is left alone
+fn release_seq_index<'info>(
+    seq_index: &AccountInfo<'info>,
+    book: &Book,
+    destination: &AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (expected_index, _) =
+        Pubkey::find_program_address(&[b"seq", &book.seq.to_le_bytes()], program_id);
+    require_keys_eq!(seq_index.key(), expected_index, DoLError::IndexMismatch);
+    if seq_index.owner != program_id {
+        return Ok(());
+    }
+    let index: SeqIndex = SeqIndex::try_deserialize(&mut &seq_index.try_borrow_data()?[..])?;
+    if index.book_id != book.id {
+        return Ok(());
     }
 
-    /// Update book metadata (super admin, admin, or curator)
-    /// Any authorized user can update any book for collective maintenance
-    pub fn update_book(
-        ctx: Context<UpdateBook>,
-        new_title: Option<String>,
-        new_author: Option<String>,
-        new_ipfs_hash: Option<String>,
-        new_genre: Option<String>,
-    ) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
+    // Close the slot the way Anchor's `close` constraint does
+    let lamports: u64 = seq_index.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(DoLError::CountOverflow)?;
+    **seq_index.try_borrow_mut_lamports()? = 0;
+    seq_index.assign(&System::id());
+    seq_index.resize(0)?;
+    Ok(())
+}
 
-        // Check if program is paused
-        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+/// Point index entries for a migrated book at its new ID, keeping their order
+fn replace_book_id(book_ids: &mut [[u8; 16]], old_id: [u8; 16], new_id: [u8; 16]) {
+    for book_id in book_ids.iter_mut().filter(|book_id| **book_id == old_id) {
+        *book_id = new_id;
+    }
+}
 
-        // Check if user has permission to update books
-        require!(
-            dol_state.can_add_books(signer), // Same permission as adding books
-            DoLError::InsufficientPermissions
-        );
+/// Map the next sequence number to a new book and advance the counter
+/// Sequence numbers are never reused, so removed books leave gaps instead of collisions
+fn index_book_by_seq<'info>(
+    seq_index: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    dol_state: &mut DoLState,
+    book_id: [u8; 16],
+) -> Result<u64> {
+    let seq: u64 = dol_state.next_book_seq;
+    let seq_bytes: [u8; 8] = seq.to_le_bytes();
+    let (expected_index, bump) = Pubkey::find_program_address(&[b"seq", &seq_bytes], program_id);
+    require_keys_eq!(seq_index.key(), expected_index, DoLError::IndexMismatch);
+
+    create_program_pda(
+        seq_index,
+        payer,
+        system_program,
+        program_id,
+        &[b"seq", &seq_bytes, &[bump]],
+        SEQ_INDEX_SPACE,
+    )?;
+    let index = SeqIndex { seq, book_id, bump };
+    let mut data = seq_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+
+    dol_state.next_book_seq = seq.checked_add(1).ok_or(DoLError::CountOverflow)?;
+    Ok(seq)
+}
 
-        // Get the book account
-        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+/// Create the title index PDA for a new book, failing if the work is already catalogued
+/// Created by hand because the IDL can't express a hashed seed for client-side resolution
+fn claim_title_index<'info>(
+    title_index: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    title: &str,
+    author: &str,
+    book_id: [u8; 16],
+) -> Result<[u8; 32]> {
+    let key: [u8; 32] = title_index_key(title, author);
+    let (expected_index, bump) = Pubkey::find_program_address(&[b"title_idx", &key], program_id);
+    require_keys_eq!(
+        title_index.key(),
+        expected_index,
+        DoLError::TitleIndexMismatch
+    );
 
-        // Update fields if provided with enhanced validation
-        if let Some(title) = new_title {
-            validate_string_input(&title, 1, 100, "title")?;
-            book.title = title;
-        }
+    // An existing index means the same title + author is already in the catalog
+    require!(title_index.owner != program_id, DoLError::BookAlreadyExists);
+
+    create_program_pda(
+        title_index,
+        payer,
+        system_program,
+        program_id,
+        &[b"title_idx", &key, &[bump]],
+        TITLE_INDEX_SPACE,
+    )?;
+
+    let index = BookTitleIndex { book_id, bump };
+    let mut data = title_index.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    index.try_serialize(&mut writer)?;
+    Ok(key)
+}
 
-        if let Some(author) = new_author {
-            validate_string_input(&author, 1, 50, "author")?;
-            book.author = author;
-        }
+// State setup helpers
+fn init_dol_state(dol_state: &mut DoLState, super_admin: Pubkey, bump: u8) -> Result<()> {
+    // init_if_needed hands back the existing state on a repeat call; never overwrite it.
+    // Version starts at 1 and only grows, so it still tells after the super admin is renounced
+    require!(dol_state.version == 0, DoLError::AlreadyInitialized);
+
+    dol_state.super_admin = super_admin;
+    dol_state.admins = Vec::new();
+    dol_state.moderators = Vec::new();
+    dol_state.curators = Vec::new();
+    dol_state.book_count = 0;
+    dol_state.version = 1;
+    dol_state.flags = 0; // Start unpaused (bit 0 = 0)
+    dol_state.bump = bump;
+    // Initialize secure transfer fields
+    dol_state.pending_super_admin = None;
+    dol_state.transfer_initiated_at = 0;
+    dol_state.transfer_timelock = 7 * 24 * 60 * 60; // 7 days in seconds
+                                                    // Initialize emergency recovery fields
+    dol_state.emergency_recovery_threshold = 2; // Require 2 admin signatures minimum
+    dol_state.emergency_recovery_initiated_at = 0;
+    dol_state.emergency_recovery_votes = Vec::new();
+    dol_state.emergency_recovery_new_admin = None;
+    // Initialize rate limiting fields
+    dol_state.last_book_addition = 0;
+    dol_state.books_added_today = 0;
+    dol_state.last_book_addition_day = 0;
+    // Initialize rating policy (re-rating disabled by default)
+    dol_state.allow_rating_update = false;
+    // Initialize pause toggle fields (no cooldown by default)
+    dol_state.pause_toggle_cooldown = 0;
+    dol_state.last_pause_change_at = 0;
+    dol_state.max_pause_duration = 0; // Pauses never lapse by default
+                                      // Initialize moderation fields (new books visible immediately)
+    dol_state.book_visibility_delay = 0;
+    // Initialize membership numbering (first card is number 1)
+    dol_state.membership_number = 0;
+    // Initialize card fee (cards are free by default)
+    dol_state.fee_lamports = 0;
+    // Initialize emergency recovery delay (super admin gets 48h to cancel)
+    dol_state.emergency_recovery_timelock = DEFAULT_RECOVERY_TIMELOCK;
+    // Initialize renunciation fields (not armed)
+    dol_state.renounce_requested_at = 0;
+    // Initialize catalog cap (unlimited)
+    dol_state.max_books = 0;
+    // Initialize card upgrade fee (upgrades are free by default)
+    dol_state.upgrade_fee_lamports = 0;
+    // Initialize role permissions (curators add and update, moderators flag and moderate)
+    dol_state.permissions = DEFAULT_PERMISSIONS;
+    // Initialize minimum card age (new cards can use community features immediately)
+    dol_state.min_card_age_seconds = 0;
+    // Initialize sequential book numbering (first book is number 0)
+    dol_state.next_book_seq = 0;
+    // Initialize the default book language (English)
+    dol_state.default_language = DEFAULT_LANGUAGE;
+    // Initialize cover policy (a cover may match the content hash)
+    dol_state.distinct_cover = false;
 
-        if let Some(ipfs_hash) = new_ipfs_hash {
-            validate_ipfs_hash_enhanced(&ipfs_hash)?;
-            book.ipfs_hash = ipfs_hash;
-        }
+    Ok(())
+}
 
-        if let Some(genre) = new_genre {
-            validate_string_input(&genre, 1, 30, "genre")?;
-            book.genre = genre;
-        }
+fn init_library_card(
+    library_card: &mut LibraryCard,
+    dol_state: &mut DoLState,
+    owner: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    library_card.owner = owner;
+    library_card.mint_timestamp = Clock::get()?.unix_timestamp;
+    library_card.bump = bump;
+    library_card.membership_number = dol_state.next_membership_number()?;
+    library_card.tier = CardTier::Standard as u8;
+
+    emit!(LibraryCardMinted {
+        owner,
+        membership_number: library_card.membership_number,
+        timestamp: library_card.mint_timestamp,
+    });
+    Ok(())
+}
 
-        msg!(
-            "Book updated: {} by {} (ID: {:?}) updated by {:?}",
-            book.title,
-            book.author,
-            &book.id[..4],
-            signer
-        );
-        Ok(())
+// Role change audit helpers
+fn emit_role_change(
+    member: Pubkey,
+    role: RoleKind,
+    added: bool,
+    changed_by: Pubkey,
+    note: String,
+) -> Result<()> {
+    if !note.is_empty() {
+        msg!("  - Note: {}", note);
     }
+    emit!(RoleChanged {
+        member,
+        role,
+        added,
+        changed_by,
+        note,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
-    /// Remove a book from the catalog (admin only)
-    /// Only admins can remove books to prevent abuse
-    pub fn remove_book(ctx: Context<RemoveBook>) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
-
-        // Check if user has admin privileges (admins or super admin only)
-        require!(
-            dol_state.has_admin_privileges(signer),
-            DoLError::InsufficientPermissions
-        );
+    Ok(())
+}
 
-        // Get the book account
-        let book: &Account<'_, Book> = &ctx.accounts.book;
+#[program]
+pub mod dol_program {
+    use super::*;
 
-        // Decrement book count
-        dol_state.book_count = dol_state.book_count.saturating_sub(1);
+    /// Initialize the DoL program with the initializing authority as super admin
+    /// This sets up the global state with role-based access control
+    /// Note: In production, use a multi-sig wallet address as the initializer
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        // The initializer becomes the super admin
+        // SECURITY: Ensure this is called with a multi-sig wallet in production
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        init_dol_state(
+            dol_state,
+            ctx.accounts.super_admin.key(),
+            ctx.bumps.dol_state,
+        )?;
 
         msg!(
-            "Book removed: {} by {} (ID: {:?}) removed by {:?}",
-            book.title,
-            book.author,
-            &book.id[..4],
-            signer
+            "DoL program initialized with super admin: {:?}",
+            dol_state.super_admin
         );
-
-        // Note: The account will be automatically closed and rent refunded to the authority
         Ok(())
     }
 
-    /// Retrieve book information (public access)
-    /// Returns complete book details including audit trail
-    pub fn get_book(ctx: Context<GetBook>) -> Result<()> {
-        // Get the book account
-        let book: &Account<'_, Book> = &ctx.accounts.book;
+    /// Mint a free Library Card NFT that grants access to read all books
+    /// Each user can only have one card (enforced by PDA seeds)
+    pub fn mint_library_card(ctx: Context<MintLibraryCard>) -> Result<()> {
+        // Check if program or card minting is paused
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_cards_paused(), DoLError::CardsPaused);
+
+        // Collect the card fee into the treasury, if one is set
+        let fee_lamports: u64 = dol_state.fee_lamports;
+        if fee_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                fee_lamports,
+            )?;
+        }
+
+        // Create the library card
+        let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
+        init_library_card(
+            library_card,
+            dol_state,
+            ctx.accounts.user.key(),
+            ctx.bumps.library_card,
+        )?;
 
-        // Print the book details
-        msg!("Book Details:");
-        msg!("- Title: {}", book.title);
-        msg!("- Author: {}", book.author);
-        msg!("- Genre: {}", book.genre);
-        msg!("- IPFS Hash: {}", book.ipfs_hash);
         msg!(
-            "- Publication Year: {}",
-            if book.publication_year > 0 {
-                book.publication_year.to_string()
-            } else {
-                "Unknown".to_string()
-            }
+            "Library card #{} minted for: {:?}",
+            library_card.membership_number,
+            library_card.owner
         );
-        msg!("- Added By: {:?}", book.added_by);
-        msg!("- Added Timestamp: {}", book.added_timestamp);
-        msg!("- Book ID: {:?}", &book.id[..8]); // Show first 8 bytes for identification
-
-        Ok(())
-    }
-
-    /// Verify that a user has a valid library card for client access control
-    pub fn verify_access(ctx: Context<VerifyAccess>) -> Result<()> {
-        // Get the library card account
-        let library_card: &Account<'_, LibraryCard> = &ctx.accounts.library_card;
-
-        // Print the card holder
-        msg!("Access verified for card holder: {:?}", library_card.owner);
         Ok(())
     }
 
-    /// Add a new admin (super admin or admin only)
-    pub fn add_admin(ctx: Context<ManageAdmin>, new_admin: Pubkey) -> Result<()> {
+    /// Set the fee charged for minting a library card (super admin only)
+    /// 0 keeps cards free; a nonzero fee must keep the treasury rent-exempt on its own
+    pub fn set_card_fee(ctx: Context<ManageAdmin>, fee_lamports: u64) -> Result<()> {
         // Get the DoL state account
         let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
         // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user has permission to manage roles
-        require!(
-            dol_state.can_manage_roles(signer),
-            DoLError::InsufficientPermissions
-        );
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
 
-        // Check if admin limit is reached
+        // The first fee paid to an empty treasury has to create it
         require!(
-            dol_state.admins.len() < MAX_ADMINS,
-            DoLError::AdminLimitReached
+            fee_lamports == 0 || fee_lamports >= Rent::get()?.minimum_balance(0),
+            DoLError::InvalidCardFee
         );
 
-        // Check if admin already exists
-        require!(
-            !dol_state.admins.contains(&new_admin),
-            DoLError::AdminAlreadyExists
-        );
+        let old_fee: u64 = dol_state.fee_lamports;
+        dol_state.fee_lamports = fee_lamports;
 
-        // Add the new admin
-        dol_state.admins.push(new_admin);
-        msg!("Admin added: {:?} by {:?}", new_admin, signer);
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Library card fee changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old fee: {} lamports", old_fee);
+        msg!("  - New fee: {} lamports", fee_lamports);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
         Ok(())
     }
 
-    /// Remove an admin (super admin only)
-    pub fn remove_admin(ctx: Context<ManageAdmin>, admin_to_remove: Pubkey) -> Result<()> {
+    /// Set the fee charged for upgrading a library card (super admin only)
+    /// Follows the same rent-exempt rule as set_card_fee
+    pub fn set_upgrade_fee(ctx: Context<ManageAdmin>, fee_lamports: u64) -> Result<()> {
         // Get the DoL state account
         let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
         // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user has is super admin
+        // Check if user is super admin
         require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
 
-        // Check if admin exists
-        if let Some(pos) = dol_state.admins.iter().position(|&x| x == admin_to_remove) {
-            // Remove the admin
-            dol_state.admins.remove(pos);
-            msg!("Admin removed: {:?} by super admin", admin_to_remove);
-        } else {
-            // Return error if admin not found
-            return Err(DoLError::AdminNotFound.into());
-        }
+        // The first fee paid to an empty treasury has to create it
+        require!(
+            fee_lamports == 0 || fee_lamports >= Rent::get()?.minimum_balance(0),
+            DoLError::InvalidCardFee
+        );
 
+        let old_fee: u64 = dol_state.upgrade_fee_lamports;
+        dol_state.upgrade_fee_lamports = fee_lamports;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Library card upgrade fee changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old fee: {} lamports", old_fee);
+        msg!("  - New fee: {} lamports", fee_lamports);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
         Ok(())
     }
 
-    /// Add a curator (super admin or admin only)
-    pub fn add_curator(ctx: Context<ManageAdmin>, new_curator: Pubkey) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
+    /// Withdraw collected card fees from the treasury to the super admin (super admin only)
+    /// The treasury must be emptied or left rent-exempt
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user has permission to manage roles
+        // Check if user is super admin
         require!(
-            dol_state.can_manage_roles(signer),
-            DoLError::InsufficientPermissions
+            ctx.accounts.dol_state.is_super_admin(signer),
+            DoLError::OnlySuperAdmin
         );
 
-        // Check if curator limit is reached
+        let balance: u64 = ctx.accounts.treasury.lamports();
         require!(
-            dol_state.curators.len() < MAX_CURATORS,
-            DoLError::CuratorLimitReached
+            amount > 0 && amount <= balance,
+            DoLError::InvalidWithdrawAmount
         );
-
-        // Check if curator already exists
+        let remaining: u64 = balance - amount;
         require!(
-            !dol_state.curators.contains(&new_curator),
-            DoLError::CuratorAlreadyExists
+            remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+            DoLError::InvalidWithdrawAmount
         );
 
-        // Add the new curator
-        dol_state.curators.push(new_curator);
-        msg!("Curator added: {:?} by {:?}", new_curator, signer);
+        let signer_seeds: &[&[u8]] = &[b"treasury", &[ctx.bumps.treasury]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+        )?;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Treasury withdrawal");
+        msg!("  - Withdrawn by: {:?}", signer);
+        msg!("  - Amount: {} lamports", amount);
+        msg!("  - Remaining: {} lamports", remaining);
+        msg!("  - Withdrawn at: {}", Clock::get()?.unix_timestamp);
         Ok(())
     }
 
-    /// Remove a curator (super admin or admin only)
-    pub fn remove_curator(ctx: Context<ManageAdmin>, curator_to_remove: Pubkey) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
+    /// Burn the signer's library card and reclaim its rent
+    /// The wallet can mint a new card afterwards since the PDA is emptied
+    pub fn burn_library_card(ctx: Context<BurnLibraryCard>) -> Result<()> {
+        let library_card: &Account<'_, LibraryCard> = &ctx.accounts.library_card;
 
-        // Check if user has permission to manage roles
-        require!(
-            dol_state.can_manage_roles(signer),
-            DoLError::InsufficientPermissions
-        );
+        msg!("Library card burned for: {:?}", library_card.owner);
+        emit!(LibraryCardBurned {
+            owner: library_card.owner,
+            mint_timestamp: library_card.mint_timestamp,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        // Check if curator exists
-        if let Some(pos) = dol_state
-            .curators
-            .iter()
-            .position(|&x| x == curator_to_remove)
-        {
-            // Remove the curator
-            dol_state.curators.remove(pos);
-            msg!("Curator removed: {:?} by {:?}", curator_to_remove, signer);
-        } else {
-            // Return error if curator not found
-            return Err(DoLError::CuratorNotFound.into());
-        }
+        // Note: The card account will be automatically closed and rent refunded to the user
+        Ok(())
+    }
 
+    /// Move the signer's library card to a new wallet (card holder only)
+    /// Membership number, mint timestamp and tier carry over; the old card is closed to its owner
+    /// Reading progress, read receipts, annotations and wishlists stay seeded by the old wallet,
+    /// so the card counts as new until CARD_TRANSFER_COOLDOWN passes (see validate_card_age)
+    pub fn transfer_card(ctx: Context<TransferCard>, new_owner: Pubkey) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_cards_paused(), DoLError::CardsPaused);
+
+        let old_card: &Account<'_, LibraryCard> = &ctx.accounts.library_card;
+        let new_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.new_library_card;
+        new_card.owner = new_owner;
+        new_card.mint_timestamp = old_card.mint_timestamp;
+        new_card.bump = ctx.bumps.new_library_card;
+        new_card.membership_number = old_card.membership_number;
+        new_card.tier = old_card.tier;
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        new_card.transferred_at = current_timestamp;
+
+        msg!(
+            "Library card #{} transferred from {:?} to {:?}",
+            new_card.membership_number,
+            old_card.owner,
+            new_owner
+        );
+        emit!(LibraryCardTransferred {
+            old_owner: old_card.owner,
+            new_owner,
+            membership_number: new_card.membership_number,
+            timestamp: current_timestamp,
+        });
+
+        // Note: The old card account will be automatically closed and rent refunded to the owner
         Ok(())
     }
 
-    /// Initiate super admin transfer (current super admin only)
-    /// Step 1: Start the timelock period for security
-    pub fn initiate_super_admin_transfer(
-        ctx: Context<ManageAdmin>,
-        new_super_admin: Pubkey,
-    ) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
-
-        // Check if user is super admin
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
-
-        // Enhanced input validation
-        validate_super_admin_address(&new_super_admin, &dol_state.super_admin, dol_state)?;
+    /// Upgrade the signer's library card to a higher tier (card holder only)
+    /// Charges upgrade_fee_lamports into the treasury when a fee is set
+    pub fn upgrade_card(ctx: Context<UpgradeCard>, tier: u8) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_cards_paused(), DoLError::CardsPaused);
 
-        // Check if there's already a pending transfer
+        // Tiers only move up; a card can't be upgraded to its current tier
+        let new_tier: CardTier = CardTier::from_u8(tier)?;
+        let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
         require!(
-            dol_state.pending_super_admin.is_none(),
-            DoLError::TransferAlreadyPending
+            new_tier > CardTier::from_u8(library_card.tier)?,
+            DoLError::InvalidCardTier
         );
 
-        // Initiate the transfer with timelock
-        dol_state.pending_super_admin = Some(new_super_admin);
-        dol_state.transfer_initiated_at = Clock::get()?.unix_timestamp;
+        // Collect the upgrade fee into the treasury, if one is set
+        let fee_lamports: u64 = dol_state.upgrade_fee_lamports;
+        if fee_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                fee_lamports,
+            )?;
+        }
 
-        // Enhanced audit logging
-        msg!("SECURITY_EVENT: Super admin transfer initiated");
-        msg!("  - Initiated by: {:?}", signer);
-        msg!("  - Current super admin: {:?}", dol_state.super_admin);
-        msg!("  - Proposed new super admin: {:?}", new_super_admin);
-        msg!(
-            "  - Timelock period: {} seconds ({} days)",
-            dol_state.transfer_timelock,
-            dol_state.transfer_timelock / (24 * 60 * 60)
-        );
-        msg!(
-            "  - Initiated at timestamp: {}",
-            dol_state.transfer_initiated_at
-        );
+        library_card.tier = tier;
         msg!(
-            "  - Can be confirmed after: {}",
-            dol_state.transfer_initiated_at + dol_state.transfer_timelock
+            "Library card #{} upgraded to {} for: {:?}",
+            library_card.membership_number,
+            new_tier.name(),
+            library_card.owner
         );
         Ok(())
     }
 
-    /// Confirm super admin transfer (current super admin only)
-    /// Step 2: Complete the transfer after timelock period
-    pub fn confirm_super_admin_transfer(ctx: Context<ManageAdmin>) -> Result<()> {
-        // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        // Get the signer
-        let signer: &Pubkey = &ctx.accounts.authority.key();
+    /// Mint Library Cards for a batch of users with an admin paying rent (admin only)
+    /// Card PDAs are passed as writable remaining accounts in the same order as `users`
+    /// Users who already hold a card are skipped
+    pub fn mint_cards_sponsored<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCardsSponsored<'info>>,
+        users: Vec<Pubkey>,
+    ) -> Result<()> {
+        let sponsor: &Pubkey = &ctx.accounts.sponsor.key();
 
-        // Check if user is super admin
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+        // Only admins can sponsor cards
+        require!(
+            ctx.accounts.dol_state.has_admin_privileges(sponsor),
+            DoLError::InsufficientPermissions
+        );
 
-        // Check if there's a pending transfer
+        // Check if program or card minting is paused
+        require!(!ctx.accounts.dol_state.is_paused(), DoLError::ProgramPaused);
         require!(
-            dol_state.pending_super_admin.is_some(),
-            DoLError::NoPendingTransfer
+            !ctx.accounts.dol_state.is_cards_paused(),
+            DoLError::CardsPaused
         );
 
-        // Check if timelock period has passed
-        let current_time: i64 = Clock::get()?.unix_timestamp;
-        let time_elapsed: i64 = current_time - dol_state.transfer_initiated_at;
+        // Bound the batch for compute
         require!(
-            time_elapsed >= dol_state.transfer_timelock,
-            DoLError::TimelockNotExpired
+            users.len() <= MAX_SPONSORED_CARDS,
+            DoLError::SponsoredBatchTooLarge
+        );
+        require!(
+            ctx.remaining_accounts.len() == users.len(),
+            DoLError::CardAccountMismatch
         );
 
-        // Complete the transfer
-        let new_super_admin: Pubkey = dol_state.pending_super_admin.unwrap();
-        let old_super_admin: Pubkey = dol_state.super_admin;
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let mut minted: u8 = 0;
+
+        for (user, card_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+            let (expected_card, bump) =
+                Pubkey::find_program_address(&[b"library_card", user.as_ref()], ctx.program_id);
+            require_keys_eq!(
+                card_info.key(),
+                expected_card,
+                DoLError::CardAccountMismatch
+            );
 
-        dol_state.super_admin = new_super_admin;
-        dol_state.pending_super_admin = None;
-        dol_state.transfer_initiated_at = 0;
+            // Skip users who already have a card
+            if card_info.owner == ctx.program_id {
+                msg!("Library card already exists for: {:?}", user);
+                continue;
+            }
+
+            // Create the card PDA with the sponsor as payer (a pre-funded address is taken over)
+            create_program_pda(
+                card_info,
+                &ctx.accounts.sponsor.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                ctx.program_id,
+                &[b"library_card", user.as_ref(), &[bump]],
+                LIBRARY_CARD_SPACE,
+            )?;
+
+            let library_card = LibraryCard {
+                owner: *user,
+                mint_timestamp: current_timestamp,
+                bump,
+                membership_number: ctx.accounts.dol_state.next_membership_number()?,
+                tier: CardTier::Standard as u8,
+                transferred_at: 0,
+                progress_count: 0,
+                review_count: 0,
+                rating_count: 0,
+                reserved: [0; 25],
+            };
+            let mut data = card_info.try_borrow_mut_data()?;
+            let mut writer: &mut [u8] = &mut data;
+            library_card.try_serialize(&mut writer)?;
+
+            emit!(LibraryCardMinted {
+                owner: *user,
+                membership_number: library_card.membership_number,
+                timestamp: current_timestamp,
+            });
+            emit!(LibraryCardSponsored {
+                owner: *user,
+                sponsor: *sponsor,
+                timestamp: current_timestamp,
+            });
+            minted += 1;
+        }
 
-        // Enhanced audit logging
-        msg!("SECURITY_EVENT: Super admin transfer completed");
-        msg!("  - Confirmed by: {:?}", signer);
-        msg!("  - Previous super admin: {:?}", old_super_admin);
-        msg!("  - New super admin: {:?}", new_super_admin);
         msg!(
-            "  - Transfer initiated at: {}",
-            Clock::get()?.unix_timestamp - dol_state.transfer_timelock
+            "Sponsored library cards minted: {} of {} by {:?}",
+            minted,
+            users.len(),
+            sponsor
         );
+        Ok(())
+    }
+
+    /// Initialize the program and mint the super admin's Library Card in one transaction
+    /// Convenience for bootstrapping and demos; fails if either PDA already exists
+    pub fn initialize_with_card(ctx: Context<InitializeWithCard>) -> Result<()> {
+        let super_admin: Pubkey = ctx.accounts.super_admin.key();
+
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        init_dol_state(dol_state, super_admin, ctx.bumps.dol_state)?;
+
+        let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
+        init_library_card(library_card, dol_state, super_admin, ctx.bumps.library_card)?;
+
         msg!(
-            "  - Transfer confirmed at: {}",
-            Clock::get()?.unix_timestamp
+            "DoL program initialized with super admin: {:?}",
+            dol_state.super_admin
         );
-        msg!("  - Timelock period elapsed: {} seconds", time_elapsed);
+        msg!("Library card minted for: {:?}", library_card.owner);
         Ok(())
     }
 
-    /// Cancel pending super admin transfer (current super admin only)
-    /// Emergency cancellation of pending transfer
-    pub fn cancel_super_admin_transfer(ctx: Context<ManageAdmin>) -> Result<()> {
+    /// Add a new book to the catalog (super admin, admin, or curator)
+    /// Books are stored with metadata pointing to IPFS content
+    /// The client must provide a unique UUID for the book ID
+    #[allow(clippy::too_many_arguments)] // Anchor passes instruction args positionally
+    pub fn add_book(
+        ctx: Context<AddBook>,
+        id: [u8; 16],
+        title: String,
+        authors: Vec<String>,
+        ipfs_hash: String,
+        genre: Genre,
+        genre_label: String,
+        metadata_ipfs_hash: String,
+        content_rating: u8,
+        page_count: u32,
+        word_count: u32,
+        oclc: u64,
+        lccn: [u8; 12],
+        publication_year: Option<u16>,
+        cover_ipfs_hash: Option<String>,
+        language: Option<[u8; 2]>,
+        isbn: Option<[u8; 13]>,
+        description: Option<String>,
+    ) -> Result<()> {
         // Get the DoL state account
         let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
         // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user is super admin
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+        // Check if program or book operations are paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_books_paused(), DoLError::BooksPaused);
 
-        // Check if there's a pending transfer
+        // Check if user has permission to add books
+        require!(dol_state.can_add_books(signer), DoLError::CannotAddBooks);
+
+        // Rate limiting checks
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let current_day: i64 = current_timestamp / 86400; // Convert to day number
+
+        // Check cooldown period
+        if dol_state.last_book_addition > 0 {
+            let time_since_last_addition: i64 = current_timestamp - dol_state.last_book_addition;
+            require!(
+                time_since_last_addition >= BOOK_ADDITION_COOLDOWN,
+                DoLError::RateLimitExceeded
+            );
+        }
+
+        // Reset daily counter if it's a new day
+        if current_day != dol_state.last_book_addition_day {
+            dol_state.books_added_today = 0;
+            dol_state.last_book_addition_day = current_day;
+        }
+
+        // Check daily limit
         require!(
-            dol_state.pending_super_admin.is_some(),
-            DoLError::NoPendingTransfer
+            dol_state.books_added_today < MAX_BOOKS_PER_DAY,
+            DoLError::DailyLimitExceeded
         );
 
-        // Cancel the transfer
-        let cancelled_transfer: Pubkey = dol_state.pending_super_admin.unwrap();
-        dol_state.pending_super_admin = None;
-        dol_state.transfer_initiated_at = 0;
+        // Check the catalog size cap
+        require!(!dol_state.is_catalog_full(), DoLError::CatalogFull);
+
+        // Check the per-curator hourly limit (admins and super admin are exempt)
+        if !dol_state.has_admin_privileges(signer) {
+            let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+            if current_timestamp - curator_stats.window_start >= CURATOR_RATE_WINDOW {
+                curator_stats.window_start = current_timestamp;
+                curator_stats.adds_in_window = 0;
+            }
+            require!(
+                curator_stats.adds_in_window < MAX_CURATOR_ADDS_PER_WINDOW,
+                DoLError::RateLimitExceeded
+            );
+            curator_stats.adds_in_window += 1;
+        }
+
+        // Validate UUID v4 format
+        validate_uuid_v4(&id)?;
+
+        // Enhanced validation for all input fields
+        validate_string_input(&title, 1, 100, StringField::Title)?;
+        validate_authors(&authors)?;
+        validate_genre(genre, &genre_label)?;
+        validate_ipfs_hash_enhanced(&ipfs_hash)?;
+        // Metadata document is optional (empty = none)
+        if !metadata_ipfs_hash.is_empty() {
+            validate_ipfs_hash_enhanced(&metadata_ipfs_hash)?;
+        }
+        validate_content_rating(content_rating)?;
+        validate_book_length(page_count, word_count)?;
+        validate_lccn(&lccn)?;
+        if let Some(year) = publication_year {
+            validate_publication_year(year, current_timestamp)?;
+        }
+        let language: [u8; 2] = dol_state.language_or_default(language);
+        validate_language(&language)?;
+        // Cover image is optional (None or empty = no cover)
+        let cover_ipfs_hash: String = cover_ipfs_hash.unwrap_or_default();
+        if !cover_ipfs_hash.is_empty() {
+            validate_ipfs_hash_enhanced(&cover_ipfs_hash)?;
+        }
+        validate_distinct_cover(dol_state, &ipfs_hash, &cover_ipfs_hash)?;
+        if let Some(isbn) = &isbn {
+            validate_isbn(isbn)?;
+        }
+        // Description is optional (None or empty = no description)
+        let description: String = description.unwrap_or_default();
+        validate_description(&description)?;
+
+        // Claim the normalized title + primary author so the same work can't be added twice
+        let title_key: [u8; 32] = claim_title_index(
+            &ctx.accounts.title_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &title,
+            &authors[0],
+            id,
+        )?;
+
+        // Record the book under its primary author and genre
+        index_book_by_author(
+            &ctx.accounts.author_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &authors[0],
+            id,
+        )?;
+        index_book_by_genre(
+            &ctx.accounts.genre_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            genre,
+            id,
+        )?;
+
+        // Give the book the next slot in the sequential index
+        let seq: u64 = index_book_by_seq(
+            &ctx.accounts.seq_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            dol_state,
+            id,
+        )?;
+
+        // Get the book account
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+
+        // Store book metadata with client-provided UUID
+        book.id = id;
+        book.title = title;
+        book.authors = authors;
+        book.ipfs_hash = ipfs_hash;
+        book.metadata_ipfs_hash = metadata_ipfs_hash;
+        book.cover_ipfs_hash = cover_ipfs_hash;
+        book.genre = genre;
+        book.genre_label = genre_label;
+        book.content_rating = content_rating;
+        book.page_count = page_count;
+        book.word_count = word_count;
+        book.oclc = oclc;
+        book.lccn = lccn;
+        book.publication_year = publication_year.unwrap_or(0); // 0 if unknown
+        book.language = language;
+        book.isbn = isbn;
+        book.description = description;
+        book.seq = seq;
+        book.title_key = title_key;
+        book.added_timestamp = Clock::get()?.unix_timestamp;
+        book.added_by = ctx.accounts.authority.key(); // Record who added the book
+        book.bump = ctx.bumps.book;
+        book.read_count = 0;
+        book.rating_sum = 0;
+        book.rating_count = 0;
+        book.rating_buckets = [0; 5];
+        book.attestation_count = 0;
+        book.review_count = 0;
+        book.superseded_by = [0; 16]; // Not superseded
+        book.last_updated_by = book.added_by; // Creation counts as the first edit
+        book.last_updated_at = book.added_timestamp;
+        book.flags = 0; // Active
+
+        // Record the book in the authority's bounded index, evicting the oldest entry when full
+        let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+        if author_books.authority == Pubkey::default() {
+            author_books.authority = *signer;
+            author_books.bump = ctx.bumps.author_books;
+        }
+        if author_books.book_ids.len() >= MAX_AUTHOR_BOOKS {
+            author_books.book_ids.remove(0);
+        }
+        author_books.book_ids.push(id);
+
+        // List the book in the catalog-wide index
+        index_book(&mut ctx.accounts.book_index, ctx.bumps.book_index, id)?;
+
+        // Credit the authority's contribution record
+        let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+        if curator_stats.curator == Pubkey::default() {
+            curator_stats.curator = *signer;
+            curator_stats.bump = ctx.bumps.curator_stats;
+        }
+        curator_stats.books_added = curator_stats
+            .books_added
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        // Increment counter for analytics
+        dol_state.book_count = dol_state
+            .book_count
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        // Update rate limiting fields
+        dol_state.last_book_addition = current_timestamp;
+        dol_state.books_added_today = dol_state
+            .books_added_today
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
 
-        // Enhanced audit logging
-        msg!("SECURITY_EVENT: Super admin transfer cancelled");
-        msg!("  - Cancelled by: {:?}", signer);
-        msg!("  - Cancelled transfer to: {:?}", cancelled_transfer);
         msg!(
-            "  - Transfer was initiated at: {}",
-            dol_state.transfer_initiated_at
+            "Book added: {} by {} (ID: {:?}) by {:?}",
+            book.title,
+            book.authors.join(", "),
+            &id[..4],
+            signer
         );
-        msg!("  - Cancelled at: {}", Clock::get()?.unix_timestamp);
+        emit!(BookAdded {
+            id,
+            title: book.title.clone(),
+            authors: book.authors.clone(),
+            genre: book.genre,
+            added_by: book.added_by,
+            timestamp: book.added_timestamp,
+        });
         Ok(())
     }
 
-    /// Initiate emergency recovery (admin only)
-    /// Used when super admin key is compromised or lost
-    pub fn initiate_emergency_recovery(
-        ctx: Context<ManageAdmin>,
-        new_super_admin: Pubkey,
+    /// Propose a new book for admin review (super admin, admin, or curator)
+    /// Metadata is validated now; nothing reaches the catalog until an admin approves it
+    #[allow(clippy::too_many_arguments)] // Anchor passes instruction args positionally
+    pub fn propose_book(
+        ctx: Context<ProposeBook>,
+        id: [u8; 16],
+        title: String,
+        authors: Vec<String>,
+        ipfs_hash: String,
+        genre: Genre,
+        genre_label: String,
+        metadata_ipfs_hash: String,
+        content_rating: u8,
+        page_count: u32,
+        word_count: u32,
+        oclc: u64,
+        lccn: [u8; 12],
+        publication_year: Option<u16>,
+        cover_ipfs_hash: Option<String>,
+        language: Option<[u8; 2]>,
+        isbn: Option<[u8; 13]>,
     ) -> Result<()> {
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
-        let signer: &Pubkey = &ctx.accounts.authority.key();
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.proposer.key();
 
-        // Only admins can initiate emergency recovery
-        require!(
-            dol_state.is_admin(signer),
-            DoLError::InsufficientPermissions
-        );
+        // Check if program or book operations are paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_books_paused(), DoLError::BooksPaused);
 
-        // Ensure there are enough admins for recovery
-        require!(
-            dol_state.admins.len() >= dol_state.emergency_recovery_threshold as usize,
-            DoLError::InsufficientAdminsForRecovery
-        );
+        // Check if user has permission to add books
+        require!(dol_state.can_add_books(signer), DoLError::CannotAddBooks);
 
-        // Validate the proposed new super admin
-        validate_super_admin_address(&new_super_admin, &dol_state.super_admin, dol_state)?;
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
 
-        // Check if recovery is not already in progress
-        require!(
-            dol_state.emergency_recovery_new_admin.is_none(),
-            DoLError::EmergencyRecoveryInProgress
-        );
+        // Same validation as add_book
+        validate_uuid_v4(&id)?;
+        validate_string_input(&title, 1, 100, StringField::Title)?;
+        validate_authors(&authors)?;
+        validate_genre(genre, &genre_label)?;
+        validate_ipfs_hash_enhanced(&ipfs_hash)?;
+        if !metadata_ipfs_hash.is_empty() {
+            validate_ipfs_hash_enhanced(&metadata_ipfs_hash)?;
+        }
+        validate_content_rating(content_rating)?;
+        validate_book_length(page_count, word_count)?;
+        validate_lccn(&lccn)?;
+        if let Some(year) = publication_year {
+            validate_publication_year(year, current_timestamp)?;
+        }
+        let language: [u8; 2] = dol_state.language_or_default(language);
+        validate_language(&language)?;
+        let cover_ipfs_hash: String = cover_ipfs_hash.unwrap_or_default();
+        if !cover_ipfs_hash.is_empty() {
+            validate_ipfs_hash_enhanced(&cover_ipfs_hash)?;
+        }
+        validate_distinct_cover(dol_state, &ipfs_hash, &cover_ipfs_hash)?;
+        if let Some(isbn) = &isbn {
+            validate_isbn(isbn)?;
+        }
 
-        // Initialize emergency recovery
-        dol_state.emergency_recovery_new_admin = Some(new_super_admin);
-        dol_state.emergency_recovery_initiated_at = Clock::get()?.unix_timestamp;
-        dol_state.emergency_recovery_votes = vec![*signer]; // First vote
+        let proposal: &mut Account<'_, BookProposal> = &mut ctx.accounts.proposal;
+        proposal.id = id;
+        proposal.proposer = *signer;
+        proposal.title = title;
+        proposal.authors = authors;
+        proposal.ipfs_hash = ipfs_hash;
+        proposal.metadata_ipfs_hash = metadata_ipfs_hash;
+        proposal.cover_ipfs_hash = cover_ipfs_hash;
+        proposal.genre = genre;
+        proposal.genre_label = genre_label;
+        proposal.content_rating = content_rating;
+        proposal.page_count = page_count;
+        proposal.word_count = word_count;
+        proposal.oclc = oclc;
+        proposal.lccn = lccn;
+        proposal.publication_year = publication_year.unwrap_or(0); // 0 if unknown
+        proposal.language = language;
+        proposal.isbn = isbn;
+        proposal.proposed_at = current_timestamp;
+        proposal.bump = ctx.bumps.proposal;
 
-        // Enhanced audit logging
-        msg!("SECURITY_EVENT: Emergency recovery initiated");
-        msg!("  - Initiated by admin: {:?}", signer);
-        msg!("  - Current super admin: {:?}", dol_state.super_admin);
-        msg!("  - Proposed new super admin: {:?}", new_super_admin);
         msg!(
-            "  - Votes required: {}",
-            dol_state.emergency_recovery_threshold
+            "Book proposed: {} by {} (ID: {:?}) by {:?}",
+            proposal.title,
+            proposal.authors.join(", "),
+            &id[..4],
+            signer
         );
-        msg!("  - Current votes: 1");
-        msg!("  - Initiated at: {}", Clock::get()?.unix_timestamp);
+        emit!(BookProposed {
+            id,
+            title: proposal.title.clone(),
+            authors: proposal.authors.clone(),
+            proposer: *signer,
+            timestamp: current_timestamp,
+        });
         Ok(())
     }
 
-    /// Vote for emergency recovery (admin only)
-    pub fn vote_emergency_recovery(ctx: Context<ManageAdmin>) -> Result<()> {
+    /// Approve a book proposal into the catalog (admin only)
+    /// The book is attributed to the proposer; the proposal is closed and its rent refunded to them
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
         let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Only admins can vote
+        // Check if program or book operations are paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_books_paused(), DoLError::BooksPaused);
+
+        // Only admins can approve proposals
         require!(
-            dol_state.is_admin(signer),
+            dol_state.has_admin_privileges(signer),
             DoLError::InsufficientPermissions
         );
 
-        // Check if recovery is in progress
+        // Approvals are catalog additions, so the global rate limits apply
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let current_day: i64 = current_timestamp / 86400;
+        if dol_state.last_book_addition > 0 {
+            require!(
+                current_timestamp - dol_state.last_book_addition >= BOOK_ADDITION_COOLDOWN,
+                DoLError::RateLimitExceeded
+            );
+        }
+        if current_day != dol_state.last_book_addition_day {
+            dol_state.books_added_today = 0;
+            dol_state.last_book_addition_day = current_day;
+        }
         require!(
-            dol_state.emergency_recovery_new_admin.is_some(),
-            DoLError::NoEmergencyRecoveryInProgress
+            dol_state.books_added_today < MAX_BOOKS_PER_DAY,
+            DoLError::DailyLimitExceeded
         );
+        require!(!dol_state.is_catalog_full(), DoLError::CatalogFull);
+
+        let proposal: &Account<'_, BookProposal> = &ctx.accounts.proposal;
+
+        // Claim the normalized title + author so the same work can't be added twice
+        let title_key: [u8; 32] = claim_title_index(
+            &ctx.accounts.title_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &proposal.title,
+            &proposal.authors[0],
+            proposal.id,
+        )?;
+
+        // Record the book under its primary author and genre
+        index_book_by_author(
+            &ctx.accounts.author_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &proposal.authors[0],
+            proposal.id,
+        )?;
+        index_book_by_genre(
+            &ctx.accounts.genre_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            proposal.genre,
+            proposal.id,
+        )?;
+        let seq: u64 = index_book_by_seq(
+            &ctx.accounts.seq_index,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            dol_state,
+            proposal.id,
+        )?;
 
-        // Check if admin has already voted
-        require!(
-            !dol_state.emergency_recovery_votes.contains(signer),
-            DoLError::AlreadyVotedForRecovery
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+
+        // Copy the already-validated metadata into the new book
+        book.id = proposal.id;
+        book.title = proposal.title.clone();
+        book.authors = proposal.authors.clone();
+        book.ipfs_hash = proposal.ipfs_hash.clone();
+        book.metadata_ipfs_hash = proposal.metadata_ipfs_hash.clone();
+        book.cover_ipfs_hash = proposal.cover_ipfs_hash.clone();
+        book.genre = proposal.genre;
+        book.genre_label = proposal.genre_label.clone();
+        book.content_rating = proposal.content_rating;
+        book.page_count = proposal.page_count;
+        book.word_count = proposal.word_count;
+        book.oclc = proposal.oclc;
+        book.lccn = proposal.lccn;
+        book.publication_year = proposal.publication_year;
+        book.language = proposal.language;
+        book.isbn = proposal.isbn;
+        book.description = String::new(); // Proposals don't carry a description
+        book.seq = seq;
+        book.title_key = title_key;
+        book.added_timestamp = current_timestamp;
+        book.added_by = proposal.proposer; // Credit the proposer, not the approver
+        book.bump = ctx.bumps.book;
+        book.read_count = 0;
+        book.rating_sum = 0;
+        book.rating_count = 0;
+        book.rating_buckets = [0; 5];
+        book.attestation_count = 0;
+        book.review_count = 0;
+        book.superseded_by = [0; 16]; // Not superseded
+        book.last_updated_by = book.added_by; // Creation counts as the first edit
+        book.last_updated_at = book.added_timestamp;
+        book.flags = 0; // Active
+
+        // Record the book in the proposer's bounded index, evicting the oldest entry when full
+        let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+        if author_books.authority == Pubkey::default() {
+            author_books.authority = proposal.proposer;
+            author_books.bump = ctx.bumps.author_books;
+        }
+        if author_books.book_ids.len() >= MAX_AUTHOR_BOOKS {
+            author_books.book_ids.remove(0);
+        }
+        author_books.book_ids.push(proposal.id);
+
+        // List the book in the catalog-wide index
+        index_book(
+            &mut ctx.accounts.book_index,
+            ctx.bumps.book_index,
+            proposal.id,
+        )?;
+
+        // Credit the proposer's contribution record
+        let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+        if curator_stats.curator == Pubkey::default() {
+            curator_stats.curator = proposal.proposer;
+            curator_stats.bump = ctx.bumps.curator_stats;
+        }
+        curator_stats.books_added = curator_stats
+            .books_added
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        dol_state.book_count = dol_state
+            .book_count
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+        dol_state.last_book_addition = current_timestamp;
+        dol_state.books_added_today = dol_state
+            .books_added_today
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        msg!(
+            "Proposal approved: {} by {} (ID: {:?}) proposed by {:?}, approved by {:?}",
+            book.title,
+            book.authors.join(", "),
+            &book.id[..4],
+            proposal.proposer,
+            signer
         );
+        emit!(BookAdded {
+            id: book.id,
+            title: book.title.clone(),
+            authors: book.authors.clone(),
+            genre: book.genre,
+            added_by: book.added_by,
+            timestamp: current_timestamp,
+        });
+
+        // Note: The proposal account will be automatically closed and rent refunded to the proposer
+        Ok(())
+    }
 
-        // Add vote
-        dol_state.emergency_recovery_votes.push(*signer);
+    /// Reject a book proposal (admin only)
+    /// Closes the proposal and refunds its rent to the proposer
+    pub fn reject_proposal(ctx: Context<RejectProposal>) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Enhanced audit logging for vote
-        msg!("SECURITY_EVENT: Emergency recovery vote added");
-        msg!("  - Vote by admin: {:?}", signer);
+        // Only admins can reject proposals
+        require!(
+            dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        let proposal: &Account<'_, BookProposal> = &ctx.accounts.proposal;
         msg!(
-            "  - Total votes: {}/{}",
-            dol_state.emergency_recovery_votes.len(),
-            dol_state.emergency_recovery_threshold
+            "Proposal rejected: {} by {} (ID: {:?}) proposed by {:?}, rejected by {:?}",
+            proposal.title,
+            proposal.authors.join(", "),
+            &proposal.id[..4],
+            proposal.proposer,
+            signer
         );
-        msg!("  - Voters: {:?}", dol_state.emergency_recovery_votes);
+        emit!(ProposalRejected {
+            id: proposal.id,
+            proposer: proposal.proposer,
+            rejected_by: *signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Note: The proposal account will be automatically closed and rent refunded to the proposer
+        Ok(())
+    }
 
-        // Check if enough votes are collected
-        if dol_state.emergency_recovery_votes.len()
-            >= dol_state.emergency_recovery_threshold as usize
-        {
-            // Execute recovery
-            let new_super_admin: Pubkey = dol_state.emergency_recovery_new_admin.unwrap();
-            let old_super_admin: Pubkey = dol_state.super_admin;
+    /// Update book metadata (super admin, admin, or curator when CURATOR_CAN_UPDATE is set)
+    /// Any authorized user can update any book for collective maintenance
+    #[allow(clippy::too_many_arguments)] // Anchor passes instruction args positionally
+    pub fn update_book(
+        ctx: Context<UpdateBook>,
+        new_title: Option<String>,
+        new_authors: Option<Vec<String>>,
+        new_ipfs_hash: Option<String>,
+        new_genre: Option<Genre>,
+        new_genre_label: Option<String>,
+        new_metadata_ipfs_hash: Option<String>,
+        new_content_rating: Option<u8>,
+        new_page_count: Option<u32>,
+        new_word_count: Option<u32>,
+        new_oclc: Option<u64>,
+        new_lccn: Option<[u8; 12]>,
+        new_publication_year: Option<u16>,
+        new_cover_ipfs_hash: Option<String>,
+        new_language: Option<[u8; 2]>,
+        new_isbn: Option<[u8; 13]>,
+        new_description: Option<String>,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
 
-            dol_state.super_admin = new_super_admin;
+        // Check if program or book operations are paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+        require!(!dol_state.is_books_paused(), DoLError::BooksPaused);
 
-            // Clear recovery state
-            dol_state.emergency_recovery_new_admin = None;
-            dol_state.emergency_recovery_initiated_at = 0;
-            dol_state.emergency_recovery_votes.clear();
+        // Check if user has permission to update books
+        require!(
+            dol_state.can_update_books(signer),
+            DoLError::InsufficientPermissions
+        );
 
-            // Enhanced audit logging for execution
-            msg!("SECURITY_EVENT: Emergency recovery executed");
-            msg!("  - Previous super admin: {:?}", old_super_admin);
-            msg!("  - New super admin: {:?}", new_super_admin);
-            msg!(
-                "  - Recovery initiated at: {}",
-                dol_state.emergency_recovery_initiated_at
-            );
-            msg!("  - Recovery executed at: {}", Clock::get()?.unix_timestamp);
-            msg!("  - Final vote by: {:?}", signer);
+        // Get the book account
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+
+        // Only admins can edit a book while it's under review
+        require!(
+            !book.is_under_review() || dol_state.has_admin_privileges(signer),
+            DoLError::BookUnderReview
+        );
+
+        // Re-check the cover policy only when either hash changes
+        let hashes_changed: bool = new_ipfs_hash.is_some() || new_cover_ipfs_hash.is_some();
+        let renamed: bool = new_title.is_some() || new_authors.is_some();
+        // Index keys the book is filed under before this update
+        let previous_author: String = book.authors[0].clone();
+        let previous_genre: Genre = book.genre;
+
+        // Update fields if provided with enhanced validation
+        if let Some(title) = new_title {
+            validate_string_input(&title, 1, 100, StringField::Title)?;
+            book.title = title;
+        }
+
+        if let Some(authors) = new_authors {
+            validate_authors(&authors)?;
+            book.authors = authors;
+        }
+
+        if let Some(ipfs_hash) = new_ipfs_hash {
+            validate_ipfs_hash_enhanced(&ipfs_hash)?;
+            book.ipfs_hash = ipfs_hash;
+        }
+
+        if new_genre.is_some() || new_genre_label.is_some() {
+            let genre: Genre = new_genre.unwrap_or(book.genre);
+            // Switching away from Other drops the old label
+            let genre_label: String = match new_genre_label {
+                Some(label) => label,
+                None if genre == Genre::Other => book.genre_label.clone(),
+                None => String::new(),
+            };
+            validate_genre(genre, &genre_label)?;
+            book.genre = genre;
+            book.genre_label = genre_label;
+        }
+
+        if let Some(metadata_ipfs_hash) = new_metadata_ipfs_hash {
+            // An empty hash clears the metadata document
+            if !metadata_ipfs_hash.is_empty() {
+                validate_ipfs_hash_enhanced(&metadata_ipfs_hash)?;
+            }
+            book.metadata_ipfs_hash = metadata_ipfs_hash;
+        }
+
+        if let Some(cover_ipfs_hash) = new_cover_ipfs_hash {
+            // An empty hash removes the cover
+            if !cover_ipfs_hash.is_empty() {
+                validate_ipfs_hash_enhanced(&cover_ipfs_hash)?;
+            }
+            book.cover_ipfs_hash = cover_ipfs_hash;
+        }
+
+        if hashes_changed {
+            validate_distinct_cover(dol_state, &book.ipfs_hash, &book.cover_ipfs_hash)?;
+        }
+
+        if let Some(content_rating) = new_content_rating {
+            validate_content_rating(content_rating)?;
+            book.content_rating = content_rating;
+        }
+
+        if new_page_count.is_some() || new_word_count.is_some() {
+            let page_count: u32 = new_page_count.unwrap_or(book.page_count);
+            let word_count: u32 = new_word_count.unwrap_or(book.word_count);
+            validate_book_length(page_count, word_count)?;
+            book.page_count = page_count;
+            book.word_count = word_count;
+        }
+
+        if let Some(oclc) = new_oclc {
+            book.oclc = oclc;
+        }
+
+        if let Some(lccn) = new_lccn {
+            validate_lccn(&lccn)?;
+            book.lccn = lccn;
+        }
+
+        if let Some(year) = new_publication_year {
+            validate_publication_year(year, Clock::get()?.unix_timestamp)?;
+            book.publication_year = year;
+        }
+
+        if let Some(language) = new_language {
+            validate_language(&language)?;
+            book.language = language;
+        }
+
+        if let Some(isbn) = new_isbn {
+            validate_isbn(&isbn)?;
+            book.isbn = Some(isbn);
+        }
+
+        if let Some(description) = new_description {
+            // An empty description clears it
+            validate_description(&description)?;
+            book.description = description;
+        }
+
+        // Grow the account if longer strings no longer fit, with the payer funding the rent
+        let required_space: usize = book_space(
+            &book.title,
+            &book.authors,
+            &book.ipfs_hash,
+            &book.metadata_ipfs_hash,
+            &book.cover_ipfs_hash,
+            &book.genre_label,
+            &book.description,
+        );
+        let book_info: AccountInfo<'_> = book.to_account_info();
+        if required_space > book_info.data_len() {
+            let (Some(payer), Some(system_program)) =
+                (&ctx.accounts.payer, &ctx.accounts.system_program)
+            else {
+                return err!(DoLError::AccountTooSmall);
+            };
+            let rent_delta: u64 = Rent::get()?
+                .minimum_balance(required_space)
+                .saturating_sub(book_info.lamports());
+            if rent_delta > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        system_program.to_account_info(),
+                        Transfer {
+                            from: payer.to_account_info(),
+                            to: book_info.clone(),
+                        },
+                    ),
+                    rent_delta,
+                )?;
+            }
+            book_info.resize(required_space)?;
+        }
+
+        // Move the title claim when the normalized title or primary author changes
+        let title_key: [u8; 32] = title_index_key(&book.title, &book.authors[0]);
+        if renamed && title_key != book.title_key {
+            let (Some(payer), Some(system_program)) =
+                (&ctx.accounts.payer, &ctx.accounts.system_program)
+            else {
+                return err!(DoLError::PayerRequired);
+            };
+            let Some(new_title_index) = &ctx.accounts.new_title_index else {
+                return err!(DoLError::IndexRequired);
+            };
+            claim_title_index(
+                new_title_index,
+                &payer.to_account_info(),
+                &system_program.to_account_info(),
+                ctx.program_id,
+                &book.title,
+                &book.authors[0],
+                book.id,
+            )?;
+            // Books added before the title index existed have no claim to release
+            if book.title_key != [0; 32] {
+                let Some(title_index) = &ctx.accounts.title_index else {
+                    return err!(DoLError::IndexRequired);
+                };
+                title_index.close(payer.to_account_info())?;
+            }
+            book.title_key = title_key;
+        }
+
+        // Move the book between author and genre indexes when the primary author or genre changes
+        let author_changed: bool =
+            author_index_key(&book.authors[0]) != author_index_key(&previous_author);
+        let genre_changed: bool = book.genre != previous_genre;
+        if author_changed || genre_changed {
+            let (Some(payer), Some(system_program)) =
+                (&ctx.accounts.payer, &ctx.accounts.system_program)
+            else {
+                return err!(DoLError::PayerRequired);
+            };
+            if author_changed {
+                let (Some(author_index), Some(new_author_index)) =
+                    (&ctx.accounts.author_index, &ctx.accounts.new_author_index)
+                else {
+                    return err!(DoLError::IndexRequired);
+                };
+                edit_author_index(author_index, ctx.program_id, &previous_author, |book_ids| {
+                    book_ids.retain(|book_id| *book_id != book.id)
+                })?;
+                index_book_by_author(
+                    new_author_index,
+                    &payer.to_account_info(),
+                    &system_program.to_account_info(),
+                    ctx.program_id,
+                    &book.authors[0],
+                    book.id,
+                )?;
+            }
+            if genre_changed {
+                let (Some(genre_index), Some(new_genre_index)) =
+                    (&ctx.accounts.genre_index, &ctx.accounts.new_genre_index)
+                else {
+                    return err!(DoLError::IndexRequired);
+                };
+                edit_genre_index(genre_index, ctx.program_id, previous_genre, |book_ids| {
+                    book_ids.retain(|book_id| *book_id != book.id)
+                })?;
+                index_book_by_genre(
+                    new_genre_index,
+                    &payer.to_account_info(),
+                    &system_program.to_account_info(),
+                    ctx.program_id,
+                    book.genre,
+                    book.id,
+                )?;
+            }
         }
 
+        // Record who touched the book last (for audit trail)
+        book.last_updated_by = *signer;
+        book.last_updated_at = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "Book updated: {} by {} (ID: {:?}) updated by {:?}",
+            book.title,
+            book.authors.join(", "),
+            &book.id[..4],
+            signer
+        );
+        emit!(BookUpdated {
+            id: book.id,
+            title: book.title.clone(),
+            authors: book.authors.clone(),
+            genre: book.genre,
+            updated_by: book.last_updated_by,
+            timestamp: book.last_updated_at,
+        });
         Ok(())
     }
 
-    /// Cancel emergency recovery (super admin only)
-    pub fn cancel_emergency_recovery(ctx: Context<ManageAdmin>) -> Result<()> {
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+    /// Reassign a book's attribution to another curator, admin, or super admin (admin only)
+    /// The original added_timestamp is preserved
+    pub fn reassign_book(ctx: Context<ReassignBook>, new_owner: Pubkey) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Only super admin can cancel recovery
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+        // Check if user has admin privileges (admins or super admin only)
+        require!(
+            dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
 
-        // Check if recovery is in progress
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        let previous_owner: Pubkey = book.added_by;
+
+        // The new owner must currently be allowed to add books
         require!(
-            dol_state.emergency_recovery_new_admin.is_some(),
-            DoLError::NoEmergencyRecoveryInProgress
+            new_owner != previous_owner && dol_state.can_add_books(&new_owner),
+            DoLError::InvalidBookOwner
         );
 
-        // Clear recovery state
-        let cancelled_recovery: Pubkey = dol_state.emergency_recovery_new_admin.unwrap();
-        dol_state.emergency_recovery_new_admin = None;
-        dol_state.emergency_recovery_initiated_at = 0;
-        dol_state.emergency_recovery_votes.clear();
+        book.added_by = new_owner;
+
+        // Move the book between the owners' bounded indexes (it may already have been evicted)
+        let previous_author_books: &mut Account<'_, AuthorBooks> =
+            &mut ctx.accounts.previous_author_books;
+        if previous_author_books.authority == Pubkey::default() {
+            previous_author_books.authority = previous_owner;
+            previous_author_books.bump = ctx.bumps.previous_author_books;
+        }
+        previous_author_books
+            .book_ids
+            .retain(|book_id| *book_id != book.id);
+        let new_author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.new_author_books;
+        if new_author_books.authority == Pubkey::default() {
+            new_author_books.authority = new_owner;
+            new_author_books.bump = ctx.bumps.new_author_books;
+        }
+        if new_author_books.book_ids.len() >= MAX_AUTHOR_BOOKS {
+            new_author_books.book_ids.remove(0);
+        }
+        new_author_books.book_ids.push(book.id);
+
+        // Move the contribution credit along with the attribution
+        let previous_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.previous_stats;
+        if previous_stats.curator == Pubkey::default() {
+            previous_stats.curator = previous_owner;
+            previous_stats.bump = ctx.bumps.previous_stats;
+        }
+        previous_stats.books_added = previous_stats.books_added.saturating_sub(1);
+        let new_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.new_stats;
+        if new_stats.curator == Pubkey::default() {
+            new_stats.curator = new_owner;
+            new_stats.bump = ctx.bumps.new_stats;
+        }
+        new_stats.books_added = new_stats
+            .books_added
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        // Record who touched the book last (for audit trail)
+        book.last_updated_by = *signer;
+        book.last_updated_at = Clock::get()?.unix_timestamp;
 
-        // Enhanced audit logging
-        msg!("SECURITY_EVENT: Emergency recovery cancelled");
-        msg!("  - Cancelled by super admin: {:?}", signer);
-        msg!("  - Cancelled recovery for: {:?}", cancelled_recovery);
         msg!(
-            "  - Recovery was initiated at: {}",
-            dol_state.emergency_recovery_initiated_at
+            "Book reassigned: {} (ID: {:?}) from {:?} to {:?} by {:?}",
+            book.title,
+            &book.id[..4],
+            previous_owner,
+            new_owner,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Put a book under review or clear the review (moderators and higher roles)
+    /// Books under review are hidden from readers and only admins can edit them
+    pub fn flag_book(ctx: Context<FlagBook>, flagged: bool) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only moderators (and higher roles) can flag books
+        require!(
+            dol_state.can_flag_books(signer),
+            DoLError::InsufficientPermissions
         );
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        if flagged {
+            book.flags |= BOOK_FLAG_UNDER_REVIEW;
+        } else {
+            book.flags &= !BOOK_FLAG_UNDER_REVIEW;
+        }
+
         msg!(
-            "  - Votes collected: {}",
-            dol_state.emergency_recovery_votes.len()
+            "Book {}: {} (ID: {:?}) by {:?}",
+            if flagged {
+                "put under review"
+            } else {
+                "cleared from review"
+            },
+            book.title,
+            &book.id[..4],
+            signer
         );
-        msg!("  - Cancelled at: {}", Clock::get()?.unix_timestamp);
+        emit!(BookReviewFlagChanged {
+            id: book.id,
+            under_review: flagged,
+            changed_by: *signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 
-    /// Pause program operations (super admin only)
-    /// Emergency stop mechanism for security incidents
-    pub fn pause_program(ctx: Context<ManageAdmin>) -> Result<()> {
+    /// Archive a book, hiding it from the catalog while keeping its record (admin only)
+    /// Recommended over `remove_book`, which destroys the audit trail
+    pub fn archive_book(ctx: Context<ArchiveBook>) -> Result<()> {
         // Get the DoL state account
-        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
         // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user is super admin
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+        // Check if user has admin privileges (admins or super admin only)
+        require!(
+            dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
 
-        // Pause the program
-        dol_state.set_paused(true);
-        msg!("Program paused by super admin: {:?}", signer);
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        require!(!book.is_archived(), DoLError::BookAlreadyArchived);
+
+        // The account stays open, so its ID can't be reused by add_book
+        book.flags |= BOOK_FLAG_ARCHIVED;
+
+        // Archived books leave the catalog-wide index of active books
+        let book_index: &mut Account<'_, BookIndex> = &mut ctx.accounts.book_index;
+        book_index.bump = ctx.bumps.book_index;
+        book_index.book_ids.retain(|book_id| *book_id != book.id);
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        msg!(
+            "Book archived: {} by {} (ID: {:?}) archived by {:?}",
+            book.title,
+            book.authors.join(", "),
+            &book.id[..4],
+            signer
+        );
+        emit!(BookArchived {
+            id: book.id,
+            archived_by: *signer,
+            timestamp: current_timestamp,
+        });
         Ok(())
     }
 
-    /// Unpause program operations (super admin only)
-    /// Resume normal operations after resolving issues
-    pub fn unpause_program(ctx: Context<ManageAdmin>) -> Result<()> {
+    /// Remove a book from the catalog (admin, or curator/moderator when permitted)
+    /// Curators and moderators need CURATOR_CAN_REMOVE / MODERATOR_CAN_REMOVE, off by default
+    pub fn remove_book(ctx: Context<RemoveBook>) -> Result<()> {
         // Get the DoL state account
         let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
         // Get the signer
         let signer: &Pubkey = &ctx.accounts.authority.key();
 
-        // Check if user is super admin
-        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+        // Admins always can; curators and moderators only when permitted
+        require!(
+            dol_state.can_remove_books(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Get the book account
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+
+        // Books holding a title claim must release it
+        require!(
+            book.title_key == [0; 32] || ctx.accounts.title_index.is_some(),
+            DoLError::IndexRequired
+        );
+
+        // Drop the book from its authority's index (it may already have been evicted)
+        let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+        if author_books.authority == Pubkey::default() {
+            author_books.authority = book.added_by;
+            author_books.bump = ctx.bumps.author_books;
+        }
+        author_books.book_ids.retain(|book_id| *book_id != book.id);
+
+        // Drop the book from the catalog-wide index
+        let book_index: &mut Account<'_, BookIndex> = &mut ctx.accounts.book_index;
+        book_index.bump = ctx.bumps.book_index;
+        book_index.book_ids.retain(|book_id| *book_id != book.id);
+
+        // Drop the book from its author and genre indexes
+        edit_author_index(
+            &ctx.accounts.author_index,
+            ctx.program_id,
+            &book.authors[0],
+            |book_ids| book_ids.retain(|book_id| *book_id != book.id),
+        )?;
+        edit_genre_index(
+            &ctx.accounts.genre_index,
+            ctx.program_id,
+            book.genre,
+            |book_ids| book_ids.retain(|book_id| *book_id != book.id),
+        )?;
+
+        // Free the book's sequence slot
+        release_seq_index(
+            &ctx.accounts.seq_index,
+            book,
+            &ctx.accounts.authority.to_account_info(),
+            ctx.program_id,
+        )?;
+
+        // Take the book off its authority's contribution record
+        let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+        if curator_stats.curator == Pubkey::default() {
+            curator_stats.curator = book.added_by;
+            curator_stats.bump = ctx.bumps.curator_stats;
+        }
+        curator_stats.books_added = curator_stats.books_added.saturating_sub(1);
+
+        // Decrement book count; an underflow means the count drifted (see reconcile_book_count)
+        dol_state.book_count = dol_state
+            .book_count
+            .checked_sub(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        msg!(
+            "Book removed: {} by {} (ID: {:?}) removed by {:?}",
+            book.title,
+            book.authors.join(", "),
+            &book.id[..4],
+            signer
+        );
+        emit!(BookRemoved {
+            id: book.id,
+            title: book.title.clone(),
+            authors: book.authors.clone(),
+            genre: book.genre,
+            added_by: book.added_by,
+            removed_by: *signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Note: The account will be automatically closed and rent refunded to the authority
+        Ok(())
+    }
+
+    /// Remove up to MAX_REMOVE_BATCH books in one transaction (same permission as remove_book)
+    /// Remaining accounts are (book, title index, author index, genre index, seq index) groups;
+    /// only books added before the title index existed may pass the program ID as their title
+    /// index. Any account that isn't a valid book PDA or one of its index PDAs fails the whole
+    /// transaction, so nothing is closed partially. The book leaves its indexes and rent goes
+    /// to the authority. Every book must have been added by `added_by`, whose AuthorBooks and
+    /// CuratorStats are passed once and kept in step as remove_book does.
+    pub fn remove_books_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveBooksBatch<'info>>,
+        added_by: Pubkey,
+    ) -> Result<()> {
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Admins always can; curators and moderators only when permitted
+        require!(
+            ctx.accounts.dol_state.can_remove_books(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Bound the batch for compute
+        let books: usize = ctx.remaining_accounts.len() / REMOVE_BATCH_BOOK_ACCOUNTS;
+        require!(
+            books > 0
+                && ctx
+                    .remaining_accounts
+                    .len()
+                    .is_multiple_of(REMOVE_BATCH_BOOK_ACCOUNTS),
+            DoLError::InvalidBatchAccounts
+        );
+        require!(books <= MAX_REMOVE_BATCH, DoLError::RemoveBatchTooLarge);
+
+        let authority_info: AccountInfo<'info> = ctx.accounts.authority.to_account_info();
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let mut removed: u64 = 0;
+
+        // The contributor's records may predate stats and the author index
+        if ctx.accounts.author_books.authority == Pubkey::default() {
+            ctx.accounts.author_books.authority = added_by;
+            ctx.accounts.author_books.bump = ctx.bumps.author_books;
+        }
+        if ctx.accounts.curator_stats.curator == Pubkey::default() {
+            ctx.accounts.curator_stats.curator = added_by;
+            ctx.accounts.curator_stats.bump = ctx.bumps.curator_stats;
+        }
+
+        for group in ctx.remaining_accounts.chunks(REMOVE_BATCH_BOOK_ACCOUNTS) {
+            // Owner and discriminator are checked on load; the address must be the book's PDA
+            let book: Account<'info, Book> =
+                Account::try_from(&group[0]).map_err(|_| DoLError::InvalidBatchAccounts)?;
+            let expected_book: Pubkey = Pubkey::create_program_address(
+                &[b"book", book.id.as_ref(), &[book.bump]],
+                ctx.program_id,
+            )
+            .map_err(|_| DoLError::InvalidBatchAccounts)?;
+            require_keys_eq!(book.key(), expected_book, DoLError::InvalidBatchAccounts);
+
+            // One contributor per batch, so their records are passed once
+            require_keys_eq!(book.added_by, added_by, DoLError::BatchAddedByMismatch);
+
+            // Release the title claim and drop the book from its other indexes
+            release_batch_indexes(&book, &group[1..], &authority_info, ctx.program_id)?;
+
+            emit!(BookRemoved {
+                id: book.id,
+                title: book.title.clone(),
+                authors: book.authors.clone(),
+                genre: book.genre,
+                added_by: book.added_by,
+                removed_by: *signer,
+                timestamp: current_timestamp,
+            });
+            // Take the book off the contributor's index and contribution record
+            let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+            author_books.book_ids.retain(|book_id| *book_id != book.id);
+            let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+            curator_stats.books_added = curator_stats.books_added.saturating_sub(1);
+            let book_index: &mut Account<'_, BookIndex> = &mut ctx.accounts.book_index;
+            book_index.book_ids.retain(|book_id| *book_id != book.id);
+            book.close(authority_info.clone())?;
+            removed += 1;
+        }
+        ctx.accounts.book_index.bump = ctx.bumps.book_index;
+
+        // Decrement book count by the number actually closed
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        dol_state.book_count = dol_state
+            .book_count
+            .checked_sub(removed)
+            .ok_or(DoLError::CountOverflow)?;
+
+        msg!("Books removed in batch: {} by {:?}", removed, signer);
+        Ok(())
+    }
+
+    /// Close up to MAX_REMOVE_BATCH books added by a compromised curator (admin only)
+    /// Remaining accounts are (book, title index, author index, genre index, seq index) groups
+    /// laid out as in remove_books_batch, and purged books leave their indexes the same way.
+    /// Books whose added_by isn't the target curator are skipped and left open, so a mixed
+    /// batch is safe. Purged books leave the curator's AuthorBooks and CuratorStats as
+    /// remove_book does. Pair with remove_curator to finish the incident response.
+    pub fn purge_curator_books<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PurgeCuratorBooks<'info>>,
+        target_curator: Pubkey,
+    ) -> Result<()> {
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has admin privileges (admins or super admin only)
+        require!(
+            ctx.accounts.dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Bound the batch for compute
+        let books: usize = ctx.remaining_accounts.len() / REMOVE_BATCH_BOOK_ACCOUNTS;
+        require!(
+            books > 0
+                && ctx
+                    .remaining_accounts
+                    .len()
+                    .is_multiple_of(REMOVE_BATCH_BOOK_ACCOUNTS),
+            DoLError::InvalidBatchAccounts
+        );
+        require!(books <= MAX_REMOVE_BATCH, DoLError::RemoveBatchTooLarge);
+
+        let authority_info: AccountInfo<'info> = ctx.accounts.authority.to_account_info();
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let mut removed: u64 = 0;
+        let mut skipped: u64 = 0;
+
+        // The curator's records may predate stats and the author index
+        if ctx.accounts.author_books.authority == Pubkey::default() {
+            ctx.accounts.author_books.authority = target_curator;
+            ctx.accounts.author_books.bump = ctx.bumps.author_books;
+        }
+        if ctx.accounts.curator_stats.curator == Pubkey::default() {
+            ctx.accounts.curator_stats.curator = target_curator;
+            ctx.accounts.curator_stats.bump = ctx.bumps.curator_stats;
+        }
+
+        for group in ctx.remaining_accounts.chunks(REMOVE_BATCH_BOOK_ACCOUNTS) {
+            // Owner and discriminator are checked on load; the address must be the book's PDA
+            let book: Account<'info, Book> =
+                Account::try_from(&group[0]).map_err(|_| DoLError::InvalidBatchAccounts)?;
+            let expected_book: Pubkey = Pubkey::create_program_address(
+                &[b"book", book.id.as_ref(), &[book.bump]],
+                ctx.program_id,
+            )
+            .map_err(|_| DoLError::InvalidBatchAccounts)?;
+            require_keys_eq!(book.key(), expected_book, DoLError::InvalidBatchAccounts);
+
+            // Leave books from other contributors alone
+            if book.added_by != target_curator {
+                skipped += 1;
+                continue;
+            }
+
+            // Release the title claim and drop the book from its other indexes
+            release_batch_indexes(&book, &group[1..], &authority_info, ctx.program_id)?;
+
+            emit!(BookRemoved {
+                id: book.id,
+                title: book.title.clone(),
+                authors: book.authors.clone(),
+                genre: book.genre,
+                added_by: book.added_by,
+                removed_by: *signer,
+                timestamp: current_timestamp,
+            });
+            // Take the book off the curator's index and contribution record
+            let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+            author_books.book_ids.retain(|book_id| *book_id != book.id);
+            let curator_stats: &mut Account<'_, CuratorStats> = &mut ctx.accounts.curator_stats;
+            curator_stats.books_added = curator_stats.books_added.saturating_sub(1);
+            let book_index: &mut Account<'_, BookIndex> = &mut ctx.accounts.book_index;
+            book_index.book_ids.retain(|book_id| *book_id != book.id);
+            book.close(authority_info.clone())?;
+            removed += 1;
+        }
+        ctx.accounts.book_index.bump = ctx.bumps.book_index;
+
+        // Decrement book count by the number actually closed
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        dol_state.book_count = dol_state
+            .book_count
+            .checked_sub(removed)
+            .ok_or(DoLError::CountOverflow)?;
+
+        msg!("SECURITY_EVENT: Curator books purged");
+        msg!("  - Curator: {:?}", target_curator);
+        msg!("  - Removed: {}", removed);
+        msg!("  - Skipped: {}", skipped);
+        msg!("  - Purged by: {:?}", signer);
+        emit!(CuratorBooksPurged {
+            curator: target_curator,
+            removed,
+            skipped,
+            purged_by: *signer,
+            timestamp: current_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Mark a book as superseded by a newer edition (admin only)
+    /// The old book stays readable; read paths surface the pointer to the new edition
+    pub fn supersede_book(
+        ctx: Context<SupersedeBook>,
+        old_id: [u8; 16],
+        new_id: [u8; 16],
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Check if user has admin privileges (admins or super admin only)
+        require!(
+            dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Point the old edition at the new one (new book existence is enforced by its PDA)
+        let old_book: &mut Account<'_, Book> = &mut ctx.accounts.old_book;
+        old_book.superseded_by = new_id;
+
+        msg!(
+            "Book superseded: {} (ID: {:?}) -> {} (ID: {:?}) by {:?}",
+            old_book.title,
+            &old_id[..4],
+            ctx.accounts.new_book.title,
+            &new_id[..4],
+            signer
+        );
+        Ok(())
+    }
+
+    /// Move a legacy book to a new UUID v4 address (super admin only)
+    /// Every field is copied, including added_by and timestamps, and the old account is closed.
+    /// Every index holding the book is repointed at the new ID. Accounts derived from the old book's
+    /// address (ratings, reviews, reports, read receipts, attestations) and other books'
+    /// superseded_by pointers still reference the old book, so the counters those records back
+    /// start over at zero instead of being counted twice by the new address.
+    pub fn migrate_book_id(ctx: Context<MigrateBookId>, new_id: [u8; 16]) -> Result<()> {
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(
+            ctx.accounts.dol_state.is_super_admin(signer),
+            DoLError::OnlySuperAdmin
+        );
+
+        // The new address must meet the rules add_book enforces today
+        validate_uuid_v4(&new_id)?;
+
+        let old_id: [u8; 16] = ctx.accounts.old_book.id;
+        let mut migrated: Book = (*ctx.accounts.old_book).clone();
+        migrated.id = new_id;
+        migrated.bump = ctx.bumps.new_book;
+        migrated.read_count = 0;
+        migrated.rating_sum = 0;
+        migrated.rating_count = 0;
+        migrated.rating_buckets = [0; 5];
+        migrated.attestation_count = 0;
+        migrated.review_count = 0;
+        ctx.accounts.new_book.set_inner(migrated);
+
+        // Repoint the indexes that store the book's ID
+        let book_index: &mut Account<'_, BookIndex> = &mut ctx.accounts.book_index;
+        book_index.bump = ctx.bumps.book_index;
+        replace_book_id(&mut book_index.book_ids, old_id, new_id);
+        let author_books: &mut Account<'_, AuthorBooks> = &mut ctx.accounts.author_books;
+        if author_books.authority == Pubkey::default() {
+            author_books.authority = ctx.accounts.old_book.added_by;
+            author_books.bump = ctx.bumps.author_books;
+        }
+        replace_book_id(&mut author_books.book_ids, old_id, new_id);
+
+        // Books holding a title claim must move it
+        let old_book: &Account<'_, Book> = &ctx.accounts.old_book;
+        if old_book.title_key != [0; 32] {
+            let Some(title_index) = &mut ctx.accounts.title_index else {
+                return err!(DoLError::IndexRequired);
+            };
+            title_index.book_id = new_id;
+        }
+        edit_author_index(
+            &ctx.accounts.author_index,
+            ctx.program_id,
+            &old_book.authors[0],
+            |book_ids| replace_book_id(book_ids, old_id, new_id),
+        )?;
+        edit_genre_index(
+            &ctx.accounts.genre_index,
+            ctx.program_id,
+            old_book.genre,
+            |book_ids| replace_book_id(book_ids, old_id, new_id),
+        )?;
+
+        // Move the sequence slot; a book added before the sequential index doesn't own one
+        let seq_info: &AccountInfo<'_> = &ctx.accounts.seq_index;
+        let (expected_index, _) =
+            Pubkey::find_program_address(&[b"seq", &old_book.seq.to_le_bytes()], ctx.program_id);
+        require_keys_eq!(seq_info.key(), expected_index, DoLError::IndexMismatch);
+        if seq_info.owner == ctx.program_id {
+            let mut seq_index: SeqIndex =
+                SeqIndex::try_deserialize(&mut &seq_info.try_borrow_data()?[..])?;
+            if seq_index.book_id == old_id {
+                seq_index.book_id = new_id;
+                let mut data = seq_info.try_borrow_mut_data()?;
+                let mut writer: &mut [u8] = &mut data;
+                seq_index.try_serialize(&mut writer)?;
+            }
+        }
+
+        msg!(
+            "Book ID migrated: {} (ID: {:?} -> {:?}) by super admin: {:?}",
+            ctx.accounts.new_book.title,
+            &old_id[..4],
+            &new_id[..4],
+            signer
+        );
+        emit!(BookIdMigrated {
+            old_id,
+            new_id,
+            migrated_by: *signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Note: The old account is closed by Anchor and its rent refunded to the super admin
+        Ok(())
+    }
+
+    /// Retrieve book information (public access)
+    /// Logs complete book details and returns a `BookSummary` as return data for CPI callers
+    pub fn get_book(ctx: Context<GetBook>) -> Result<BookSummary> {
+        // Get the book account
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+
+        // Print the book details
+        msg!("Book Details:");
+        msg!("- Title: {}", book.title);
+        msg!("- Authors: {}", book.authors.join(", "));
+        msg!(
+            "- Genre: {}",
+            if book.genre == Genre::Other {
+                &book.genre_label
+            } else {
+                book.genre.name()
+            }
+        );
+        msg!(
+            "- Content Rating: {}",
+            match book.content_rating {
+                CONTENT_RATING_EVERYONE => "Everyone",
+                CONTENT_RATING_TEEN => "Teen",
+                CONTENT_RATING_MATURE => "Mature",
+                _ => "Unrated",
+            }
+        );
+        msg!("- IPFS Hash: {}", book.ipfs_hash);
+        msg!(
+            "- Metadata IPFS Hash: {}",
+            if book.metadata_ipfs_hash.is_empty() {
+                "None"
+            } else {
+                &book.metadata_ipfs_hash
+            }
+        );
+        msg!(
+            "- Cover IPFS Hash: {}",
+            if book.cover_ipfs_hash.is_empty() {
+                "None"
+            } else {
+                &book.cover_ipfs_hash
+            }
+        );
+        msg!(
+            "- Publication Year: {}",
+            if book.publication_year > 0 {
+                book.publication_year.to_string()
+            } else {
+                "Unknown".to_string()
+            }
+        );
+        msg!(
+            "- Language: {}",
+            String::from_utf8_lossy(&book.language_code())
+        );
+        msg!(
+            "- Description: {}",
+            if book.description.is_empty() {
+                "None"
+            } else {
+                &book.description
+            }
+        );
+        msg!("- Page Count: {}", book.page_count);
+        msg!("- Word Count: {}", book.word_count);
+        let est_reading_minutes: Option<u32> = book.est_reading_minutes(DEFAULT_READING_WPM);
+        if let Some(minutes) = est_reading_minutes {
+            msg!("- Est. Reading Time: ~{} min", minutes);
+        }
+        if book.oclc != 0 {
+            msg!("- OCLC: {}", book.oclc);
+        }
+        if let Some(isbn) = &book.isbn {
+            msg!("- ISBN: {}", String::from_utf8_lossy(isbn));
+        }
+        if book.lccn != [0; 12] {
+            let len: usize = book.lccn.iter().position(|&b| b == 0).unwrap_or(12);
+            msg!("- LCCN: {}", String::from_utf8_lossy(&book.lccn[..len]));
+        }
+        msg!("- Added By: {:?}", book.added_by);
+        msg!("- Added Timestamp: {}", book.added_timestamp);
+        msg!("- Last Updated By: {:?}", book.last_updated_by);
+        msg!("- Last Updated At: {}", book.last_updated_at);
+        msg!("- Read Count: {}", book.read_count);
+        msg!("- Reviewed By: {} curators", book.attestation_count);
+        if book.rating_count > 0 {
+            // Two decimal places without floating point
+            let average_x100: u64 = book.rating_sum * 100 / book.rating_count as u64;
+            msg!(
+                "- Average Rating: {}.{:02} ({} ratings)",
+                average_x100 / 100,
+                average_x100 % 100,
+                book.rating_count
+            );
+        } else {
+            msg!("- Average Rating: Unrated");
+        }
+        if book.superseded_by != [0; 16] {
+            msg!("- Superseded By: {:?}", book.superseded_by); // Full ID of the newer edition
+        }
+        msg!(
+            "- Status: {}",
+            if book.is_archived() {
+                "Archived"
+            } else if book.is_under_review() {
+                "Under Review"
+            } else if book.is_flagged() {
+                "Flagged"
+            } else {
+                "Active"
+            }
+        );
+        msg!("- Book ID: {:?}", &book.id[..8]); // Show first 8 bytes for identification
+
+        // Anchor serializes the returned summary into the transaction's return data
+        Ok(BookSummary {
+            id: book.id,
+            title: book.title.clone(),
+            ipfs_hash: book.ipfs_hash.clone(),
+            genre: book.genre,
+            publication_year: book.publication_year,
+            page_count: book.page_count,
+            word_count: book.word_count,
+            est_reading_minutes,
+            under_review: book.is_under_review(),
+            last_updated_at: book.last_updated_at,
+            last_updated_by: book.last_updated_by,
+            archived: book.is_archived(),
+            superseded_by: book.superseded_by,
+        })
+    }
+
+    /// Retrieve an active book (public access)
+    /// Same as `get_book`, but fails with `BookArchived` so reader clients never surface archived content
+    pub fn get_active_book(ctx: Context<GetBook>) -> Result<BookSummary> {
+        require!(!ctx.accounts.book.is_archived(), DoLError::BookArchived);
+
+        get_book(ctx)
+    }
+
+    /// Retrieve the full provenance record of a book (public access)
+    /// Consolidates every audit field for compliance tooling in a single read
+    pub fn get_book_audit(ctx: Context<GetBook>) -> Result<BookAudit> {
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+
+        let audit = BookAudit {
+            id: book.id,
+            added_by: book.added_by,
+            added_timestamp: book.added_timestamp,
+            last_updated_by: book.last_updated_by,
+            last_updated_at: book.last_updated_at,
+            attestation_count: book.attestation_count,
+            superseded_by: book.superseded_by,
+            flags: book.flags,
+        };
+
+        msg!(
+            "Book audit: added by {:?} at {}, last updated by {:?} at {}, flags {:#04b}",
+            audit.added_by,
+            audit.added_timestamp,
+            audit.last_updated_by,
+            audit.last_updated_at,
+            audit.flags
+        );
+        Ok(audit)
+    }
+
+    /// Export a book as a deployment-scoped portable record (public access)
+    /// Returns the borsh-serialized Book followed by the super admin and program ID
+    /// Fails with ExportTooLarge when the record doesn't fit in return data (e.g. long descriptions)
+    pub fn export_book(ctx: Context<ExportBook>, id: [u8; 16]) -> Result<Vec<u8>> {
+        // Validate UUID v4 format (the seeds constraint already ties it to the PDA)
+        validate_uuid_v4(&id)?;
+
+        let book: &Book = &ctx.accounts.book;
+        let mut record: Vec<u8> = Vec::new();
+        book.serialize(&mut record)?;
+        record.extend_from_slice(ctx.accounts.dol_state.super_admin.as_ref());
+        record.extend_from_slice(ctx.program_id.as_ref());
+
+        // Return data holds the borsh Vec<u8>: a u32 length prefix plus the record
+        require!(
+            4 + record.len() <= MAX_RETURN_DATA,
+            DoLError::ExportTooLarge
+        );
+
+        msg!(
+            "Book exported: {} (ID: {:?}) as {} bytes",
+            book.title,
+            &id[..4],
+            record.len()
+        );
+        Ok(record)
+    }
+
+    /// Return the 1-5 star histogram of a book's current ratings (public access)
+    pub fn get_rating_distribution(
+        ctx: Context<GetRatingDistribution>,
+        book_id: [u8; 16],
+    ) -> Result<RatingDistribution> {
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+
+        msg!(
+            "Rating distribution for {:?}: {:?} ({} ratings)",
+            &book_id[..4],
+            book.rating_buckets,
+            book.rating_count
+        );
+        Ok(RatingDistribution {
+            book_id,
+            buckets: book.rating_buckets,
+            rating_count: book.rating_count,
+        })
+    }
+
+    /// Record that a library card holder read a book
+    /// The first read creates a receipt and counts; repeat reads only count after a cooldown
+    /// Reads from a recently transferred card are recorded but not counted
+    pub fn record_read(ctx: Context<RecordRead>) -> Result<()> {
+        // Check if program is paused
+        require!(!ctx.accounts.dol_state.is_paused(), DoLError::ProgramPaused);
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_book_visible(
+            &ctx.accounts.dol_state,
+            &ctx.accounts.book,
+            &ctx.accounts.reader.key(),
+            current_timestamp,
+        )?;
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        let read_receipt: &mut Account<'_, ReadReceipt> = &mut ctx.accounts.read_receipt;
+
+        // A zeroed first_read_at means the receipt was just created
+        let is_first_read: bool = read_receipt.first_read_at == 0;
+        let counts: bool = !is_recently_transferred(
+            &ctx.accounts.dol_state,
+            &ctx.accounts.library_card,
+            current_timestamp,
+        ) && (is_first_read
+            || current_timestamp - read_receipt.last_read_at >= READ_COUNT_COOLDOWN);
+
+        if is_first_read {
+            read_receipt.reader = ctx.accounts.reader.key();
+            read_receipt.book_id = book.id;
+            read_receipt.first_read_at = current_timestamp;
+            read_receipt.bump = ctx.bumps.read_receipt;
+        }
+        read_receipt.last_read_at = current_timestamp;
+
+        if counts {
+            book.read_count = book
+                .read_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+        }
+
+        msg!(
+            "Read recorded: {} (ID: {:?}) by {:?} (counted: {}, total reads: {})",
+            book.title,
+            &book.id[..4],
+            read_receipt.reader,
+            counts,
+            book.read_count
+        );
+        Ok(())
+    }
+
+    /// Save how far a library card holder has read a book (0-100%)
+    /// The first update creates the progress record so reading can resume on any device
+    pub fn update_progress(ctx: Context<UpdateProgress>, percent: u8) -> Result<()> {
+        // Check if program is paused
+        require!(!ctx.accounts.dol_state.is_paused(), DoLError::ProgramPaused);
+
+        require!(percent <= 100, DoLError::InvalidProgress);
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_book_visible(
+            &ctx.accounts.dol_state,
+            &ctx.accounts.book,
+            &ctx.accounts.reader.key(),
+            current_timestamp,
+        )?;
+
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+        let progress: &mut Account<'_, ReadingProgress> = &mut ctx.accounts.progress;
+
+        // A default reader means the record was just created
+        if progress.reader == Pubkey::default() {
+            progress.reader = ctx.accounts.reader.key();
+            progress.book_id = book.id;
+            progress.bump = ctx.bumps.progress;
+
+            let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
+            library_card.progress_count = library_card
+                .progress_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+        }
+        progress.percent = percent;
+        progress.updated_at = current_timestamp;
+
+        msg!(
+            "Reading progress: {} (ID: {:?}) by {:?} at {}%",
+            book.title,
+            &book.id[..4],
+            progress.reader,
+            percent
+        );
+        Ok(())
+    }
+
+    /// Attest that the signing curator reviewed a book's content (curator only)
+    /// One attestation per curator per book, counted on the book as a trust signal
+    pub fn attest_book(ctx: Context<AttestBook>, book_id: [u8; 16]) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.curator.key();
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Only curators can attest reviews
+        require!(
+            dol_state.is_curator(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Record the attestation
+        let attestation: &mut Account<'_, ReviewAttestation> = &mut ctx.accounts.attestation;
+        attestation.book_id = book_id;
+        attestation.curator = *signer;
+        attestation.reviewed_at = Clock::get()?.unix_timestamp;
+        attestation.bump = ctx.bumps.attestation;
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        book.attestation_count = book
+            .attestation_count
+            .checked_add(1)
+            .ok_or(DoLError::CountOverflow)?;
+
+        msg!(
+            "Book attested: {} (ID: {:?}) reviewed by curator {:?} ({} total)",
+            book.title,
+            &book_id[..4],
+            signer,
+            book.attestation_count
+        );
+        Ok(())
+    }
+
+    /// Revoke the signing curator's review attestation for a book
+    /// Former curators may still revoke their own attestations
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>, book_id: [u8; 16]) -> Result<()> {
+        // Check if program is paused
+        require!(!ctx.accounts.dol_state.is_paused(), DoLError::ProgramPaused);
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        book.attestation_count = book.attestation_count.saturating_sub(1);
+
+        msg!(
+            "Attestation revoked: {} (ID: {:?}) by curator {:?} ({} remaining)",
+            book.title,
+            &book_id[..4],
+            ctx.accounts.curator.key(),
+            book.attestation_count
+        );
+
+        // Note: The attestation account will be automatically closed and rent refunded to the curator
+        Ok(())
+    }
+
+    /// Rate a book from 1 to 5 stars (library card holders only)
+    /// Each card holder gets one rating per book; re-rating follows `allow_rating_update`
+    pub fn rate_book(ctx: Context<RateBook>, rating: u8) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Validate rating range
+        require!(
+            (MIN_RATING..=MAX_RATING).contains(&rating),
+            DoLError::InvalidRating
+        );
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_card_age(dol_state, &ctx.accounts.library_card, current_timestamp)?;
+        validate_book_visible(
+            dol_state,
+            &ctx.accounts.book,
+            &ctx.accounts.rater.key(),
+            current_timestamp,
+        )?;
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        let rating_receipt: &mut Account<'_, RatingReceipt> = &mut ctx.accounts.rating_receipt;
+
+        // A zero rating means the receipt was just created
+        let previous_rating: u8 = rating_receipt.rating;
+        if previous_rating == 0 {
+            book.rating_sum = book
+                .rating_sum
+                .checked_add(rating as u64)
+                .ok_or(DoLError::CountOverflow)?;
+            book.rating_count = book
+                .rating_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+
+            rating_receipt.rater = ctx.accounts.rater.key();
+            rating_receipt.book_id = book.id;
+            rating_receipt.bump = ctx.bumps.rating_receipt;
+
+            let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
+            library_card.rating_count = library_card
+                .rating_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+        } else {
+            // Re-rating replaces the previous score instead of adding a new one
+            require!(dol_state.allow_rating_update, DoLError::AlreadyRated);
+            book.rating_sum = book
+                .rating_sum
+                .checked_sub(previous_rating as u64)
+                .and_then(|sum| sum.checked_add(rating as u64))
+                .ok_or(DoLError::CountOverflow)?;
+            // Move the rater's vote out of its previous star bucket
+            let previous_bucket: &mut u32 =
+                &mut book.rating_buckets[(previous_rating - 1) as usize];
+            *previous_bucket = previous_bucket
+                .checked_sub(1)
+                .ok_or(DoLError::CountOverflow)?;
+        }
+
+        let bucket: &mut u32 = &mut book.rating_buckets[(rating - 1) as usize];
+        *bucket = bucket.checked_add(1).ok_or(DoLError::CountOverflow)?;
+
+        rating_receipt.rating = rating;
+        rating_receipt.rated_at = current_timestamp;
+
+        msg!(
+            "Book rated: {} (ID: {:?}) {} stars by {:?} (previous: {})",
+            book.title,
+            &book.id[..4],
+            rating,
+            rating_receipt.rater,
+            previous_rating
+        );
+        Ok(())
+    }
+
+    /// Post or edit a short written review of a book (library card holders only)
+    /// Each card holder gets one review per book; posting again replaces it
+    pub fn post_review(ctx: Context<PostReview>, text: String, stars: u8) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Validate review content
+        validate_string_input(&text, 1, MAX_REVIEW_TEXT_LEN, StringField::Review)?;
+        require!(
+            (MIN_RATING..=MAX_RATING).contains(&stars),
+            DoLError::InvalidRating
+        );
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_card_age(dol_state, &ctx.accounts.library_card, current_timestamp)?;
+        validate_book_visible(
+            dol_state,
+            &ctx.accounts.book,
+            &ctx.accounts.reviewer.key(),
+            current_timestamp,
+        )?;
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        let review: &mut Account<'_, Review> = &mut ctx.accounts.review;
+
+        // A zeroed created_at means the review was just created
+        let is_new: bool = review.created_at == 0;
+        if is_new {
+            book.review_count = book
+                .review_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+
+            review.reviewer = ctx.accounts.reviewer.key();
+            review.book_id = book.id;
+            review.created_at = current_timestamp;
+            review.bump = ctx.bumps.review;
+
+            let library_card: &mut Account<'_, LibraryCard> = &mut ctx.accounts.library_card;
+            library_card.review_count = library_card
+                .review_count
+                .checked_add(1)
+                .ok_or(DoLError::CountOverflow)?;
+        }
+        review.text = text;
+        review.stars = stars;
+
+        msg!(
+            "Review {}: {} (ID: {:?}) {} stars by {:?}",
+            if is_new { "posted" } else { "edited" },
+            book.title,
+            &book.id[..4],
+            stars,
+            review.reviewer
+        );
+        Ok(())
+    }
+
+    /// Delete a review (its author or a moderator)
+    /// Rent is always refunded to the review's author
+    pub fn delete_review(ctx: Context<DeleteReview>, book_id: [u8; 16]) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only the author or moderators (and higher roles) can delete
+        require!(
+            *signer == ctx.accounts.review.reviewer || dol_state.can_delete_reviews(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        book.review_count = book.review_count.saturating_sub(1);
+
+        // The author's card drops the review too, unless it has since moved wallets
+        edit_library_card(
+            &ctx.accounts.library_card,
+            ctx.program_id,
+            &ctx.accounts.review.reviewer,
+            |card| card.review_count = card.review_count.saturating_sub(1),
+        )?;
+
+        msg!(
+            "Review deleted: {} (ID: {:?}) by {:?} ({} remaining)",
+            book.title,
+            &book_id[..4],
+            signer,
+            book.review_count
+        );
+
+        // Note: The review account will be automatically closed and rent refunded to its author
+        Ok(())
+    }
+
+    /// Report a book for moderator review (library card holders only)
+    /// Each card holder can have one open report per book
+    pub fn report_book(ctx: Context<ReportBook>, reason_code: u8, details: String) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Validate report content
+        require!(
+            reason_code <= REPORT_REASON_OTHER,
+            DoLError::InvalidReportReason
+        );
+        validate_string_input(&details, 1, MAX_REPORT_DETAILS_LEN, StringField::Report)?;
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_card_age(dol_state, &ctx.accounts.library_card, current_timestamp)?;
+
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+        let report: &mut Account<'_, BookReport> = &mut ctx.accounts.report;
+        report.reporter = ctx.accounts.reporter.key();
+        report.book = book.key();
+        report.reason_code = reason_code;
+        report.details = details;
+        report.created_at = current_timestamp;
+        report.bump = ctx.bumps.report;
+
+        msg!(
+            "Book reported: {} (ID: {:?}) reason {} by {:?}",
+            book.title,
+            &book.id[..4],
+            reason_code,
+            report.reporter
+        );
+        Ok(())
+    }
+
+    /// Resolve a report by dismissing it or flagging the book (moderators and higher roles)
+    /// Rent is always refunded to the reporter
+    pub fn resolve_report(ctx: Context<ResolveReport>, action: u8) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only moderators (and higher roles) can resolve reports
+        require!(
+            dol_state.can_resolve_reports(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        let book: &mut Account<'_, Book> = &mut ctx.accounts.book;
+        match action {
+            REPORT_ACTION_DISMISS => {}
+            REPORT_ACTION_FLAG => book.flags |= BOOK_FLAG_FLAGGED,
+            _ => return err!(DoLError::InvalidReportAction),
+        }
+
+        msg!(
+            "Report {}: {} (ID: {:?}) reported by {:?}, resolved by {:?}",
+            if action == REPORT_ACTION_FLAG {
+                "upheld"
+            } else {
+                "dismissed"
+            },
+            book.title,
+            &book.id[..4],
+            ctx.accounts.report.reporter,
+            signer
+        );
+
+        // Note: The report account will be automatically closed and rent refunded to the reporter
+        Ok(())
+    }
+
+    /// Create a named collection of books (library card holders only)
+    /// The client passes the SHA-256 of the name, which seeds the collection PDA
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name_hash: [u8; 32],
+        name: String,
+        description: String,
+    ) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Validate collection content
+        validate_string_input(
+            &name,
+            1,
+            MAX_COLLECTION_NAME_LEN,
+            StringField::CollectionName,
+        )?;
+        require!(
+            hash(name.as_bytes()).to_bytes() == name_hash,
+            DoLError::CollectionNameMismatch
+        );
+        // Description is optional (empty = none)
+        if !description.is_empty() {
+            validate_string_input(
+                &description,
+                1,
+                MAX_COLLECTION_DESCRIPTION_LEN,
+                StringField::CollectionDescription,
+            )?;
+        }
+
+        let collection: &mut Account<'_, Collection> = &mut ctx.accounts.collection;
+        collection.creator = ctx.accounts.creator.key();
+        collection.name_hash = name_hash;
+        collection.name = name;
+        collection.description = description;
+        collection.created_at = Clock::get()?.unix_timestamp;
+        collection.bump = ctx.bumps.collection;
+
+        msg!(
+            "Collection created: {} by {:?}",
+            collection.name,
+            collection.creator
+        );
+        Ok(())
+    }
+
+    /// Add a catalog book to one of the signer's collections (library card holders only)
+    pub fn add_book_to_collection(ctx: Context<AddBookToCollection>) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Only live catalog books can be collected
+        let book: &Account<'_, Book> = &ctx.accounts.book;
+        require!(!book.is_archived(), DoLError::BookAlreadyArchived);
+
+        let collection: &mut Account<'_, Collection> = &mut ctx.accounts.collection;
+        require!(
+            !collection.book_ids.contains(&book.id),
+            DoLError::BookAlreadyInCollection
+        );
+        require!(
+            collection.book_ids.len() < MAX_COLLECTION_BOOKS,
+            DoLError::CollectionFull
+        );
+        collection.book_ids.push(book.id);
+
+        msg!(
+            "Book added to collection {}: {} (ID: {:?})",
+            collection.name,
+            book.title,
+            &book.id[..4]
+        );
+        Ok(())
+    }
+
+    /// Remove a book from one of the signer's collections (library card holders only)
+    /// Takes the ID rather than the book account so removed books can still be dropped
+    pub fn remove_book_from_collection(
+        ctx: Context<RemoveBookFromCollection>,
+        book_id: [u8; 16],
+    ) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        let collection: &mut Account<'_, Collection> = &mut ctx.accounts.collection;
+        let position: usize = collection
+            .book_ids
+            .iter()
+            .position(|id| *id == book_id)
+            .ok_or(DoLError::BookNotInCollection)?;
+        collection.book_ids.remove(position);
+
+        msg!(
+            "Book removed from collection {}: {:?}",
+            collection.name,
+            &book_id[..4]
+        );
+        Ok(())
+    }
+
+    /// Create an annotation or highlight on a book (library card holders only)
+    /// Each reader has MAX_ANNOTATIONS_PER_BOOK numbered slots per book
+    pub fn create_annotation(
+        ctx: Context<CreateAnnotation>,
+        index: u16,
+        start_offset: u32,
+        end_offset: u32,
+        note: String,
+    ) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        require!(
+            index < MAX_ANNOTATIONS_PER_BOOK,
+            DoLError::InvalidAnnotationIndex
+        );
+        validate_annotation(start_offset, end_offset, &note)?;
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        validate_book_visible(
+            dol_state,
+            &ctx.accounts.book,
+            &ctx.accounts.reader.key(),
+            current_timestamp,
+        )?;
+
+        let annotation: &mut Account<'_, Annotation> = &mut ctx.accounts.annotation;
+        annotation.reader = ctx.accounts.reader.key();
+        annotation.book_id = ctx.accounts.book.id;
+        annotation.index = index;
+        annotation.start_offset = start_offset;
+        annotation.end_offset = end_offset;
+        annotation.note = note;
+        annotation.created_at = current_timestamp;
+        annotation.updated_at = current_timestamp;
+        annotation.bump = ctx.bumps.annotation;
+
+        emit!(AnnotationSaved {
+            reader: annotation.reader,
+            book_id: annotation.book_id,
+            index,
+            start_offset,
+            end_offset,
+            note: annotation.note.clone(),
+            timestamp: current_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Move an annotation's range or edit its note (its reader, with a library card)
+    pub fn update_annotation(
+        ctx: Context<UpdateAnnotation>,
+        book_id: [u8; 16],
+        index: u16,
+        start_offset: u32,
+        end_offset: u32,
+        note: String,
+    ) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        validate_annotation(start_offset, end_offset, &note)?;
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let annotation: &mut Account<'_, Annotation> = &mut ctx.accounts.annotation;
+        annotation.start_offset = start_offset;
+        annotation.end_offset = end_offset;
+        annotation.note = note;
+        annotation.updated_at = current_timestamp;
+
+        emit!(AnnotationSaved {
+            reader: annotation.reader,
+            book_id,
+            index,
+            start_offset,
+            end_offset,
+            note: annotation.note.clone(),
+            timestamp: current_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Delete an annotation (its reader, with a library card)
+    /// Addressed by book ID, so annotations on books that were since removed can still be cleared
+    pub fn delete_annotation(
+        ctx: Context<DeleteAnnotation>,
+        book_id: [u8; 16],
+        index: u16,
+    ) -> Result<()> {
+        emit!(AnnotationDeleted {
+            reader: ctx.accounts.reader.key(),
+            book_id,
+            index,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Note: The annotation account will be automatically closed and rent refunded to the reader
+        Ok(())
+    }
+
+    /// Bookmark a catalog book to read later (library card holders only)
+    /// The member's wishlist is created on first use
+    pub fn add_to_wishlist(ctx: Context<AddToWishlist>, book_id: [u8; 16]) -> Result<()> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        // Check if program is paused
+        require!(!dol_state.is_paused(), DoLError::ProgramPaused);
+
+        // Only live catalog books can be wishlisted
+        require!(
+            !ctx.accounts.book.is_archived(),
+            DoLError::BookAlreadyArchived
+        );
+
+        let wishlist: &mut Account<'_, Wishlist> = &mut ctx.accounts.wishlist;
+        if wishlist.owner == Pubkey::default() {
+            wishlist.owner = ctx.accounts.user.key();
+            wishlist.bump = ctx.bumps.wishlist;
+        }
+        require!(
+            !wishlist.book_ids.contains(&book_id),
+            DoLError::BookAlreadyInWishlist
+        );
+        require!(
+            wishlist.book_ids.len() < MAX_WISHLIST_BOOKS,
+            DoLError::WishlistFull
+        );
+        wishlist.book_ids.push(book_id);
+
+        msg!(
+            "Book wishlisted: {} (ID: {:?}) by {:?}",
+            ctx.accounts.book.title,
+            &book_id[..4],
+            wishlist.owner
+        );
+        Ok(())
+    }
+
+    /// Drop a book from the member's wishlist (library card holders only)
+    /// Works by ID alone, so books removed from the catalog can still be cleared out
+    pub fn remove_from_wishlist(ctx: Context<RemoveFromWishlist>, book_id: [u8; 16]) -> Result<()> {
+        let wishlist: &mut Account<'_, Wishlist> = &mut ctx.accounts.wishlist;
+        let position: usize = wishlist
+            .book_ids
+            .iter()
+            .position(|id| *id == book_id)
+            .ok_or(DoLError::BookNotInWishlist)?;
+        wishlist.book_ids.remove(position);
+
+        msg!(
+            "Book removed from wishlist: {:?} by {:?}",
+            &book_id[..4],
+            wishlist.owner
+        );
+        Ok(())
+    }
+
+    /// Retrieve a reader's receipt for a book (public access)
+    /// Lets clients show "you've read this" state
+    pub fn get_read_receipt(ctx: Context<GetReadReceipt>) -> Result<ReadReceiptInfo> {
+        // Get the read receipt account
+        let read_receipt: &Account<'_, ReadReceipt> = &ctx.accounts.read_receipt;
+
+        msg!("Read Receipt:");
+        msg!("- Reader: {:?}", read_receipt.reader);
+        msg!("- Book ID: {:?}", &read_receipt.book_id[..8]);
+        msg!("- First Read At: {}", read_receipt.first_read_at);
+        msg!("- Last Read At: {}", read_receipt.last_read_at);
+
+        Ok(ReadReceiptInfo {
+            reader: read_receipt.reader,
+            book_id: read_receipt.book_id,
+            first_read_at: read_receipt.first_read_at,
+            last_read_at: read_receipt.last_read_at,
+        })
+    }
+
+    /// Resolve a wallet's highest role in one read (public access)
+    /// Precedence is super admin, admin, moderator, curator, then card-holding member
+    pub fn get_user_role(ctx: Context<GetUserRole>, user: Pubkey) -> Result<UserRole> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        let role: UserRole = if dol_state.is_super_admin(&user) {
+            UserRole::SuperAdmin
+        } else if dol_state.is_admin(&user) {
+            UserRole::Admin
+        } else if dol_state.is_moderator(&user) {
+            UserRole::Moderator
+        } else if dol_state.is_curator(&user) {
+            UserRole::Curator
+        } else if ctx.accounts.library_card.is_some() {
+            UserRole::Member
+        } else {
+            UserRole::None
+        };
+
+        msg!("User role for {:?}: {:?}", user, role);
+        Ok(role)
+    }
+
+    /// Summarize a user's library footprint in one read (public access)
+    /// Optional accounts that are omitted (or don't exist yet) report as empty
+    /// Reading progress, review, and rating totals come from the library card's counters
+    pub fn get_user_profile(ctx: Context<GetUserProfile>, user: Pubkey) -> Result<UserProfile> {
+        let library_card: &Option<Account<'_, LibraryCard>> = &ctx.accounts.library_card;
+        let curator_stats: &Option<Account<'_, CuratorStats>> = &ctx.accounts.curator_stats;
+        let wishlist: &Option<Account<'_, Wishlist>> = &ctx.accounts.wishlist;
+
+        let profile = UserProfile {
+            user,
+            has_card: library_card.is_some(),
+            card_minted_at: library_card.as_ref().map_or(0, |card| card.mint_timestamp),
+            books_added: curator_stats.as_ref().map_or(0, |stats| stats.books_added),
+            wishlist_count: wishlist
+                .as_ref()
+                .map_or(0, |list| list.book_ids.len() as u32),
+            progress_count: library_card.as_ref().map_or(0, |card| card.progress_count),
+            review_count: library_card.as_ref().map_or(0, |card| card.review_count),
+            rating_count: library_card.as_ref().map_or(0, |card| card.rating_count),
+        };
+
+        msg!(
+            "User profile for {:?}: card {}, {} books added, {} wishlisted",
+            user,
+            profile.has_card,
+            profile.books_added,
+            profile.wishlist_count
+        );
+        Ok(profile)
+    }
+
+    /// List a page of book IDs added by an authority (public access)
+    /// Only the most recent MAX_AUTHOR_BOOKS additions per authority are retained
+    pub fn list_author_books(
+        ctx: Context<ListAuthorBooks>,
+        authority: Pubkey,
+        offset: u32,
+        limit: u8,
+    ) -> Result<Vec<[u8; 16]>> {
+        // Get the author books index
+        let author_books: &Account<'_, AuthorBooks> = &ctx.accounts.author_books;
+
+        // Clamp the page to what fits in return data
+        let limit: usize = limit.min(MAX_PAGE_SIZE) as usize;
+        let page: Vec<[u8; 16]> = author_books
+            .book_ids
+            .iter()
+            .skip(offset as usize)
+            .take(limit)
+            .copied()
+            .collect();
+
+        msg!(
+            "Author books for {:?}: {} of {} (offset {})",
+            authority,
+            page.len(),
+            author_books.book_ids.len(),
+            offset
+        );
+        Ok(page)
+    }
+
+    /// List active book IDs from the catalog-wide index, oldest first (public access)
+    /// Pages are capped at MAX_PAGE_SIZE entries
+    pub fn list_books(ctx: Context<ListBooks>, offset: u32, limit: u8) -> Result<Vec<[u8; 16]>> {
+        let book_index: &Account<'_, BookIndex> = &ctx.accounts.book_index;
+
+        // Clamp the page to what fits in return data
+        let limit: usize = limit.min(MAX_PAGE_SIZE) as usize;
+        let page: Vec<[u8; 16]> = book_index
+            .book_ids
+            .iter()
+            .skip(offset as usize)
+            .take(limit)
+            .copied()
+            .collect();
+
+        msg!(
+            "Catalog books: {} of {} (offset {})",
+            page.len(),
+            book_index.book_ids.len(),
+            offset
+        );
+        Ok(page)
+    }
+
+    /// Verify that a user has a valid library card for client access control
+    /// Returns a single boolean byte as return data so other programs can gate on it via CPI
+    /// A card below `min_tier` fails with InsufficientTier (pass 0 to accept any card)
+    pub fn verify_access(ctx: Context<VerifyAccess>, user: Pubkey, min_tier: u8) -> Result<bool> {
+        let min_tier: CardTier = CardTier::from_u8(min_tier)?;
+
+        // The card is optional so a missing card reads as false instead of failing the CPI
+        let has_access: bool = match &ctx.accounts.library_card {
+            Some(library_card) if library_card.owner == user => {
+                require!(
+                    CardTier::from_u8(library_card.tier)? >= min_tier,
+                    DoLError::InsufficientTier
+                );
+                true
+            }
+            _ => false,
+        };
+
+        msg!("Access for {:?}: {}", user, has_access);
+        Ok(has_access)
+    }
+
+    /// Verify that the signer holds their own library card
+    /// The PDA seeds bind the card to the signer, so no owner pubkey is passed
+    pub fn verify_own_card(ctx: Context<VerifyOwnCard>) -> Result<bool> {
+        // Get the library card account
+        let library_card: &Account<'_, LibraryCard> = &ctx.accounts.library_card;
+
+        // Cards never expire or get revoked, so a card owned by the signer is valid
+        let is_valid: bool = library_card.owner == ctx.accounts.owner.key();
+
+        msg!(
+            "Own card verified for {:?}: {}",
+            library_card.owner,
+            is_valid
+        );
+        Ok(is_valid)
+    }
+
+    /// Add a new admin (super admin or admin only)
+    pub fn add_admin(ctx: Context<ManageAdmin>, new_admin: Pubkey, note: String) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        validate_role_note(&note)?;
+        validate_role_address(dol_state, &new_admin, RoleKind::Admin)?;
+
+        // Check if admin limit is reached
+        require!(
+            dol_state.admins.len() < MAX_ADMINS,
+            DoLError::AdminLimitReached
+        );
+
+        // Check if admin already exists
+        require!(
+            !dol_state.admins.contains(&new_admin),
+            DoLError::AdminAlreadyExists
+        );
+
+        // Add the new admin
+        dol_state.admins.push(new_admin);
+        msg!("Admin added: {:?} by {:?}", new_admin, signer);
+        emit_role_change(new_admin, RoleKind::Admin, true, *signer, note)?;
+        Ok(())
+    }
+
+    /// Remove an admin (super admin only)
+    /// Refuses to leave fewer admins than the emergency recovery threshold; lower it first
+    pub fn remove_admin(
+        ctx: Context<ManageAdmin>,
+        admin_to_remove: Pubkey,
+        note: String,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        validate_role_note(&note)?;
+
+        // Check if admin exists
+        if let Some(pos) = dol_state.admins.iter().position(|&x| x == admin_to_remove) {
+            // Keep enough admins to reach the emergency recovery threshold
+            require!(
+                dol_state.admins.len() > dol_state.emergency_recovery_threshold as usize,
+                DoLError::WouldBreakRecoveryQuorum
+            );
+            // Remove the admin
+            dol_state.admins.remove(pos);
+            msg!("Admin removed: {:?} by super admin", admin_to_remove);
+        } else {
+            // Return error if admin not found
+            return Err(DoLError::AdminNotFound.into());
+        }
+
+        emit_role_change(admin_to_remove, RoleKind::Admin, false, *signer, note)?;
+
+        Ok(())
+    }
+
+    /// Add a curator (super admin or admin only)
+    pub fn add_curator(ctx: Context<ManageAdmin>, new_curator: Pubkey, note: String) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        validate_role_note(&note)?;
+        validate_role_address(dol_state, &new_curator, RoleKind::Curator)?;
+
+        // Check if curator limit is reached
+        require!(
+            dol_state.curators.len() < MAX_CURATORS,
+            DoLError::CuratorLimitReached
+        );
+
+        // Check if curator already exists
+        require!(
+            !dol_state.curators.contains(&new_curator),
+            DoLError::CuratorAlreadyExists
+        );
+
+        // Add the new curator
+        dol_state.curators.push(new_curator);
+        msg!("Curator added: {:?} by {:?}", new_curator, signer);
+        emit_role_change(new_curator, RoleKind::Curator, true, *signer, note)?;
+        Ok(())
+    }
+
+    /// Remove a curator (super admin or admin only)
+    /// The last curator may be removed: proposals are optional (there is no proposal_mode), and
+    /// admins can still propose, approve, and add books, so the catalog never loses its path in
+    pub fn remove_curator(
+        ctx: Context<ManageAdmin>,
+        curator_to_remove: Pubkey,
+        note: String,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        validate_role_note(&note)?;
+
+        // Check if curator exists
+        if let Some(pos) = dol_state
+            .curators
+            .iter()
+            .position(|&x| x == curator_to_remove)
+        {
+            // Remove the curator
+            dol_state.curators.remove(pos);
+            msg!("Curator removed: {:?} by {:?}", curator_to_remove, signer);
+        } else {
+            // Return error if curator not found
+            return Err(DoLError::CuratorNotFound.into());
+        }
+
+        emit_role_change(curator_to_remove, RoleKind::Curator, false, *signer, note)?;
+
+        Ok(())
+    }
+
+    /// Add a moderator (super admin or admin only)
+    /// Roles are additive: an admin or curator may also be a moderator
+    pub fn add_moderator(
+        ctx: Context<ManageAdmin>,
+        new_moderator: Pubkey,
+        note: String,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        validate_role_note(&note)?;
+        validate_role_address(dol_state, &new_moderator, RoleKind::Moderator)?;
+
+        // Check if moderator limit is reached
+        require!(
+            dol_state.moderators.len() < MAX_MODERATORS,
+            DoLError::ModeratorLimitReached
+        );
+
+        // Check if moderator already exists
+        require!(
+            !dol_state.moderators.contains(&new_moderator),
+            DoLError::ModeratorAlreadyExists
+        );
+
+        // Add the new moderator
+        dol_state.moderators.push(new_moderator);
+        msg!("Moderator added: {:?} by {:?}", new_moderator, signer);
+        if dol_state.has_admin_privileges(&new_moderator) || dol_state.is_curator(&new_moderator) {
+            // Dual roles are allowed; each role's permissions apply independently
+            msg!("  - Also holds an admin or curator role");
+        }
+        emit_role_change(new_moderator, RoleKind::Moderator, true, *signer, note)?;
+        Ok(())
+    }
+
+    /// Remove a moderator (super admin or admin only)
+    pub fn remove_moderator(
+        ctx: Context<ManageAdmin>,
+        moderator_to_remove: Pubkey,
+        note: String,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        validate_role_note(&note)?;
+
+        // Check if moderator exists
+        if let Some(pos) = dol_state
+            .moderators
+            .iter()
+            .position(|&x| x == moderator_to_remove)
+        {
+            // Remove the moderator (any other roles they hold are unaffected)
+            dol_state.moderators.remove(pos);
+            msg!(
+                "Moderator removed: {:?} by {:?}",
+                moderator_to_remove,
+                signer
+            );
+        } else {
+            // Return error if moderator not found
+            return Err(DoLError::ModeratorNotFound.into());
+        }
+
+        emit_role_change(
+            moderator_to_remove,
+            RoleKind::Moderator,
+            false,
+            *signer,
+            note,
+        )?;
+
+        Ok(())
+    }
+
+    /// Step down from every admin, curator, and moderator role the signer holds
+    /// The super admin must use the transfer or renounce flow instead, and an admin can't
+    /// resign while that would leave fewer admins than the emergency recovery threshold
+    pub fn resign_role(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: Pubkey = ctx.accounts.authority.key();
+
+        require!(
+            !dol_state.is_super_admin(&signer),
+            DoLError::SuperAdminCannotResign
+        );
+
+        // Roles are additive, so drop the signer from each list they appear in
+        let mut resigned: Vec<RoleKind> = Vec::new();
+        if let Some(pos) = dol_state.admins.iter().position(|&x| x == signer) {
+            // Same recovery quorum guard as remove_admin
+            require!(
+                dol_state.admins.len() > dol_state.emergency_recovery_threshold as usize,
+                DoLError::WouldBreakRecoveryQuorum
+            );
+            dol_state.admins.remove(pos);
+            resigned.push(RoleKind::Admin);
+        }
+        if let Some(pos) = dol_state.curators.iter().position(|&x| x == signer) {
+            dol_state.curators.remove(pos);
+            resigned.push(RoleKind::Curator);
+        }
+        if let Some(pos) = dol_state.moderators.iter().position(|&x| x == signer) {
+            dol_state.moderators.remove(pos);
+            resigned.push(RoleKind::Moderator);
+        }
+        require!(!resigned.is_empty(), DoLError::NotInAnyRole);
+
+        msg!("Role resigned: {:?} stepped down", signer);
+        for role in resigned {
+            emit_role_change(signer, role, false, signer, String::new())?;
+        }
+
+        Ok(())
+    }
+
+    /// Initiate super admin transfer (current super admin only)
+    /// Step 1: Start the timelock period for security
+    pub fn initiate_super_admin_transfer(
+        ctx: Context<ManageAdmin>,
+        new_super_admin: Pubkey,
+    ) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Enhanced input validation
+        validate_super_admin_address(&new_super_admin, &dol_state.super_admin, dol_state)?;
+
+        // Check if there's already a pending transfer
+        require!(
+            dol_state.pending_super_admin.is_none(),
+            DoLError::TransferAlreadyPending
+        );
+
+        // Initiate the transfer with timelock
+        dol_state.pending_super_admin = Some(new_super_admin);
+        dol_state.transfer_initiated_at = Clock::get()?.unix_timestamp;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Super admin transfer initiated");
+        msg!("  - Initiated by: {:?}", signer);
+        msg!("  - Current super admin: {:?}", dol_state.super_admin);
+        msg!("  - Proposed new super admin: {:?}", new_super_admin);
+        msg!(
+            "  - Timelock period: {} seconds ({} days)",
+            dol_state.transfer_timelock,
+            dol_state.transfer_timelock / (24 * 60 * 60)
+        );
+        msg!(
+            "  - Initiated at timestamp: {}",
+            dol_state.transfer_initiated_at
+        );
+        msg!(
+            "  - Can be confirmed after: {}",
+            dol_state.transfer_initiated_at + dol_state.transfer_timelock
+        );
+        emit!(SuperAdminTransferInitiated {
+            super_admin: dol_state.super_admin,
+            new_super_admin,
+            confirmable_at: dol_state.transfer_initiated_at + dol_state.transfer_timelock,
+            timestamp: dol_state.transfer_initiated_at,
+        });
+        Ok(())
+    }
+
+    /// Confirm super admin transfer (pending super admin only)
+    /// Step 2: The incoming super admin claims the role after the timelock period,
+    /// proving the new key is controlled by someone
+    pub fn confirm_super_admin_transfer(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if there's a pending transfer
+        require!(
+            dol_state.pending_super_admin.is_some(),
+            DoLError::NoPendingTransfer
+        );
+
+        // Only the incoming super admin can claim the role
+        require!(
+            dol_state.pending_super_admin == Some(*signer),
+            DoLError::NotPendingSuperAdmin
+        );
+
+        // Check if timelock period has passed
+        let current_time: i64 = Clock::get()?.unix_timestamp;
+        let time_elapsed: i64 = current_time - dol_state.transfer_initiated_at;
+        require!(
+            time_elapsed >= dol_state.transfer_timelock,
+            DoLError::TimelockNotExpired
+        );
+
+        // Complete the transfer
+        let new_super_admin: Pubkey = dol_state.pending_super_admin.unwrap();
+        let old_super_admin: Pubkey = dol_state.super_admin;
+        let initiated_at: i64 = dol_state.transfer_initiated_at;
+
+        dol_state.super_admin = new_super_admin;
+        dol_state.pending_super_admin = None;
+        dol_state.transfer_initiated_at = 0;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Super admin transfer completed");
+        msg!("  - Confirmed by: {:?}", signer);
+        msg!("  - Previous super admin: {:?}", old_super_admin);
+        msg!("  - New super admin: {:?}", new_super_admin);
+        msg!("  - Transfer initiated at: {}", initiated_at);
+        msg!("  - Transfer confirmed at: {}", current_time);
+        msg!("  - Timelock period elapsed: {} seconds", time_elapsed);
+        emit!(SuperAdminTransferConfirmed {
+            old_super_admin,
+            new_super_admin,
+            initiated_at,
+            timestamp: current_time,
+        });
+        Ok(())
+    }
+
+    /// Cancel pending super admin transfer (current super admin only)
+    /// Emergency cancellation of pending transfer
+    pub fn cancel_super_admin_transfer(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Check if there's a pending transfer
+        require!(
+            dol_state.pending_super_admin.is_some(),
+            DoLError::NoPendingTransfer
+        );
+
+        // Cancel the transfer
+        let cancelled_transfer: Pubkey = dol_state.pending_super_admin.unwrap();
+        let initiated_at: i64 = dol_state.transfer_initiated_at;
+        dol_state.pending_super_admin = None;
+        dol_state.transfer_initiated_at = 0;
+
+        // Enhanced audit logging
+        let current_time: i64 = Clock::get()?.unix_timestamp;
+        msg!("SECURITY_EVENT: Super admin transfer cancelled");
+        msg!("  - Cancelled by: {:?}", signer);
+        msg!("  - Cancelled transfer to: {:?}", cancelled_transfer);
+        msg!("  - Transfer was initiated at: {}", initiated_at);
+        msg!("  - Cancelled at: {}", current_time);
+        emit!(SuperAdminTransferCancelled {
+            cancelled_by: *signer,
+            cancelled_super_admin: cancelled_transfer,
+            initiated_at,
+            timestamp: current_time,
+        });
+        Ok(())
+    }
+
+    /// Permanently give up the super admin role (super admin only)
+    /// IRREVERSIBLE: the first call arms it, a second call within RENOUNCE_CONFIRM_WINDOW
+    /// sets super_admin to the default key. Everything gated on the super admin (pausing,
+    /// role management, fees, treasury, timelocks, cancelling recoveries) is then disabled
+    /// for good. Admin-level functions keep working, and admins can still install a new
+    /// super admin through emergency recovery votes.
+    pub fn renounce_super_admin(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // A pending handover must be resolved first
+        require!(
+            dol_state.pending_super_admin.is_none(),
+            DoLError::TransferAlreadyPending
+        );
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        let armed: bool = dol_state.renounce_requested_at != 0
+            && current_timestamp <= dol_state.renounce_requested_at + RENOUNCE_CONFIRM_WINDOW;
+        if !armed {
+            dol_state.renounce_requested_at = current_timestamp;
+
+            msg!("SECURITY_EVENT: Super admin renunciation requested");
+            msg!("  - Requested by: {:?}", signer);
+            msg!(
+                "  - Call again before {} to confirm (irreversible)",
+                current_timestamp + RENOUNCE_CONFIRM_WINDOW
+            );
+            return Ok(());
+        }
+
+        dol_state.super_admin = Pubkey::default();
+        dol_state.renounce_requested_at = 0;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Super admin renounced");
+        msg!("  - Renounced by: {:?}", signer);
+        msg!("  - Remaining admins: {}", dol_state.admins.len());
+        msg!("  - Renounced at: {}", current_timestamp);
+        Ok(())
+    }
+
+    /// Set the super admin transfer timelock (super admin only)
+    /// Cannot change while a transfer is pending so its rules stay fixed
+    pub fn set_transfer_timelock(ctx: Context<ManageAdmin>, new_timelock: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Don't move the rules underneath an in-flight transfer
+        require!(
+            dol_state.pending_super_admin.is_none(),
+            DoLError::TransferAlreadyPending
+        );
+
+        require!(
+            (MIN_TRANSFER_TIMELOCK..=MAX_TRANSFER_TIMELOCK).contains(&new_timelock),
+            DoLError::InvalidTimelock
+        );
+
+        let old_timelock: i64 = dol_state.transfer_timelock;
+        dol_state.transfer_timelock = new_timelock;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Super admin transfer timelock changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old timelock: {} seconds", old_timelock);
+        msg!("  - New timelock: {} seconds", new_timelock);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Bump the program version marker after a migration (super admin only)
+    /// Versions only move forward so instructions can branch on legacy layouts safely
+    pub fn set_version(ctx: Context<ManageAdmin>, new_version: u8) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Only monotonic increases
+        require!(new_version > dol_state.version, DoLError::InvalidVersion);
+
+        let old_version: u8 = dol_state.version;
+        dol_state.version = new_version;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Program version bumped");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old version: {}", old_version);
+        msg!("  - New version: {}", new_version);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Overwrite book_count with a verified total of Book accounts (super admin only)
+    /// Repairs drift once checked updates start failing with CountOverflow
+    pub fn reconcile_book_count(ctx: Context<ManageAdmin>, actual: u64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        let old_count: u64 = dol_state.book_count;
+        dol_state.book_count = actual;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Book count reconciled");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old count: {}", old_count);
+        msg!("  - New count: {}", actual);
+        msg!(
+            "  - Delta: {:+}",
+            i128::from(actual) - i128::from(old_count)
+        );
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Set how many admin votes emergency recovery requires (super admin only)
+    pub fn set_recovery_threshold(ctx: Context<ManageAdmin>, threshold: u8) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // An active recovery can't have its bar moved mid-vote
+        require!(
+            dol_state.emergency_recovery_new_admin.is_none(),
+            DoLError::EmergencyRecoveryInProgress
+        );
+
+        require!(
+            threshold >= 1 && threshold as usize <= MAX_ADMINS,
+            DoLError::InvalidRecoveryThreshold
+        );
+
+        let old_threshold: u8 = dol_state.emergency_recovery_threshold;
+        dol_state.emergency_recovery_threshold = threshold;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Emergency recovery threshold changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old threshold: {}", old_threshold);
+        msg!("  - New threshold: {}", threshold);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Initiate emergency recovery (admin only)
+    /// Used when super admin key is compromised or lost
+    pub fn initiate_emergency_recovery(
+        ctx: Context<ManageAdmin>,
+        new_super_admin: Pubkey,
+    ) -> Result<()> {
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only admins can initiate emergency recovery
+        require!(
+            dol_state.is_admin(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Ensure there are enough admins for recovery
+        require!(
+            dol_state.is_recovery_feasible(),
+            DoLError::InsufficientAdminsForRecovery
+        );
+
+        // Validate the proposed new super admin
+        validate_super_admin_address(&new_super_admin, &dol_state.super_admin, dol_state)?;
+
+        // Check if recovery is not already in progress
+        require!(
+            dol_state.emergency_recovery_new_admin.is_none(),
+            DoLError::EmergencyRecoveryInProgress
+        );
+
+        // Initialize emergency recovery
+        dol_state.emergency_recovery_new_admin = Some(new_super_admin);
+        dol_state.emergency_recovery_initiated_at = Clock::get()?.unix_timestamp;
+        dol_state.emergency_recovery_votes = vec![*signer]; // First vote
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Emergency recovery initiated");
+        msg!("  - Initiated by admin: {:?}", signer);
+        msg!("  - Current super admin: {:?}", dol_state.super_admin);
+        msg!("  - Proposed new super admin: {:?}", new_super_admin);
+        msg!(
+            "  - Votes required: {}",
+            dol_state.emergency_recovery_threshold
+        );
+        msg!("  - Current votes: 1");
+        msg!(
+            "  - Initiated at: {}",
+            dol_state.emergency_recovery_initiated_at
+        );
+        emit!(EmergencyRecoveryInitiated {
+            initiated_by: *signer,
+            super_admin: dol_state.super_admin,
+            new_super_admin,
+            threshold: dol_state.emergency_recovery_threshold,
+            executable_at: dol_state.emergency_recovery_initiated_at
+                + dol_state.emergency_recovery_timelock,
+            timestamp: dol_state.emergency_recovery_initiated_at,
+        });
+        Ok(())
+    }
+
+    /// Vote for emergency recovery (admin only)
+    pub fn vote_emergency_recovery(ctx: Context<ManageAdmin>) -> Result<()> {
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only admins can vote
+        require!(
+            dol_state.is_admin(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Check if recovery is in progress, keeping its details for the audit log
+        let Some(new_super_admin) = dol_state.emergency_recovery_new_admin else {
+            return err!(DoLError::NoEmergencyRecoveryInProgress);
+        };
+        let initiated_at: i64 = dol_state.emergency_recovery_initiated_at;
+
+        // Check if admin has already voted
+        require!(
+            !dol_state.emergency_recovery_votes.contains(signer),
+            DoLError::AlreadyVotedForRecovery
+        );
+
+        // Add vote
+        dol_state.emergency_recovery_votes.push(*signer);
+
+        // Enhanced audit logging for vote
+        msg!("SECURITY_EVENT: Emergency recovery vote added");
+        msg!("  - Vote by admin: {:?}", signer);
+        msg!("  - Proposed super admin: {:?}", new_super_admin);
+        msg!("  - Recovery initiated at: {}", initiated_at);
+        msg!(
+            "  - Total votes: {}/{}",
+            dol_state.emergency_recovery_votes.len(),
+            dol_state.emergency_recovery_threshold
+        );
+        msg!("  - Voters: {:?}", dol_state.emergency_recovery_votes);
+        emit!(EmergencyRecoveryVoted {
+            voter: *signer,
+            new_super_admin,
+            votes: dol_state.emergency_recovery_votes.len() as u8,
+            threshold: dol_state.emergency_recovery_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        // Execution is deferred so the super admin has time to cancel
+        if dol_state.emergency_recovery_votes.len()
+            >= dol_state.emergency_recovery_threshold as usize
+        {
+            msg!("SECURITY_EVENT: Emergency recovery threshold reached");
+            msg!(
+                "  - Executable at: {}",
+                initiated_at + dol_state.emergency_recovery_timelock
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Execute an emergency recovery once it has enough votes and its timelock has passed (admin only)
+    pub fn execute_emergency_recovery(ctx: Context<ManageAdmin>) -> Result<()> {
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only admins can execute
+        require!(
+            dol_state.is_admin(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        // Check if recovery is in progress
+        let Some(new_super_admin) = dol_state.emergency_recovery_new_admin else {
+            return err!(DoLError::NoEmergencyRecoveryInProgress);
+        };
+
+        // Votes may have been pruned since the threshold was first reached
+        require!(
+            dol_state.emergency_recovery_votes.len()
+                >= dol_state.emergency_recovery_threshold as usize,
+            DoLError::InsufficientRecoveryVotes
+        );
+
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        require!(
+            current_timestamp
+                >= dol_state.emergency_recovery_initiated_at
+                    + dol_state.emergency_recovery_timelock,
+            DoLError::RecoveryTimelockNotExpired
+        );
+
+        let old_super_admin: Pubkey = dol_state.super_admin;
+        let initiated_at: i64 = dol_state.emergency_recovery_initiated_at;
+
+        dol_state.super_admin = new_super_admin;
+        // The target may have been made an admin after the proposal; keep roles disjoint
+        dol_state.admins.retain(|admin| *admin != new_super_admin);
+
+        // Clear recovery state
+        dol_state.emergency_recovery_new_admin = None;
+        dol_state.emergency_recovery_initiated_at = 0;
+        dol_state.emergency_recovery_votes.clear();
+
+        // Enhanced audit logging for execution
+        msg!("SECURITY_EVENT: Emergency recovery executed");
+        msg!("  - Previous super admin: {:?}", old_super_admin);
+        msg!("  - New super admin: {:?}", new_super_admin);
+        msg!("  - Recovery initiated at: {}", initiated_at);
+        msg!("  - Recovery executed at: {}", current_timestamp);
+        msg!("  - Executed by: {:?}", signer);
+        emit!(EmergencyRecoveryExecuted {
+            executed_by: *signer,
+            old_super_admin,
+            new_super_admin,
+            initiated_at,
+            timestamp: current_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Set how long an emergency recovery waits after initiation before it can execute (super admin only)
+    /// Cannot change while a recovery is in progress so its rules stay fixed
+    pub fn set_recovery_timelock(ctx: Context<ManageAdmin>, new_timelock: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Don't move the rules underneath an in-flight recovery
+        require!(
+            dol_state.emergency_recovery_new_admin.is_none(),
+            DoLError::EmergencyRecoveryInProgress
+        );
+
+        require!(
+            (0..=MAX_RECOVERY_TIMELOCK).contains(&new_timelock),
+            DoLError::InvalidRecoveryTimelock
+        );
+
+        let old_timelock: i64 = dol_state.emergency_recovery_timelock;
+        dol_state.emergency_recovery_timelock = new_timelock;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Emergency recovery timelock changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old timelock: {} seconds", old_timelock);
+        msg!("  - New timelock: {} seconds", new_timelock);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Cancel emergency recovery (super admin only)
+    pub fn cancel_emergency_recovery(ctx: Context<ManageAdmin>) -> Result<()> {
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only super admin can cancel recovery
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Check if recovery is in progress
+        require!(
+            dol_state.emergency_recovery_new_admin.is_some(),
+            DoLError::NoEmergencyRecoveryInProgress
+        );
+
+        // Clear recovery state
+        let cancelled_recovery: Pubkey = dol_state.emergency_recovery_new_admin.unwrap();
+        let initiated_at: i64 = dol_state.emergency_recovery_initiated_at;
+        let votes: u8 = dol_state.emergency_recovery_votes.len() as u8;
+        dol_state.emergency_recovery_new_admin = None;
+        dol_state.emergency_recovery_initiated_at = 0;
+        dol_state.emergency_recovery_votes.clear();
+
+        // Enhanced audit logging
+        let current_time: i64 = Clock::get()?.unix_timestamp;
+        msg!("SECURITY_EVENT: Emergency recovery cancelled");
+        msg!("  - Cancelled by super admin: {:?}", signer);
+        msg!("  - Cancelled recovery for: {:?}", cancelled_recovery);
+        msg!("  - Recovery was initiated at: {}", initiated_at);
+        msg!("  - Votes collected: {}", votes);
+        msg!("  - Cancelled at: {}", current_time);
+        emit!(EmergencyRecoveryCancelled {
+            cancelled_by: *signer,
+            cancelled_super_admin: cancelled_recovery,
+            votes,
+            initiated_at,
+            timestamp: current_time,
+        });
+        Ok(())
+    }
+
+    /// Prune emergency recovery votes cast by accounts that are no longer admins
+    /// (super admin or admin only). An in-progress recovery stays pending either way,
+    /// so it remains actionable once valid votes reach the threshold.
+    pub fn prune_recovery_votes(ctx: Context<ManageAdmin>) -> Result<()> {
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has permission to manage roles
+        require!(
+            dol_state.can_manage_roles(signer),
+            DoLError::CannotManageRoles
+        );
+
+        // Split votes into those from current admins and stale ones
+        let admins: Vec<Pubkey> = dol_state.admins.clone();
+        let (valid_votes, pruned_voters): (Vec<Pubkey>, Vec<Pubkey>) = dol_state
+            .emergency_recovery_votes
+            .iter()
+            .partition(|voter| admins.contains(voter));
+        dol_state.emergency_recovery_votes = valid_votes;
+
+        let recovery_in_progress: bool = dol_state.emergency_recovery_new_admin.is_some();
+        let remaining_votes: u8 = dol_state.emergency_recovery_votes.len() as u8;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Emergency recovery votes pruned");
+        msg!("  - Pruned by: {:?}", signer);
+        msg!("  - Pruned voters: {:?}", pruned_voters);
+        msg!(
+            "  - Remaining votes: {}/{}",
+            remaining_votes,
+            dol_state.emergency_recovery_threshold
+        );
+        msg!("  - Recovery in progress: {}", recovery_in_progress);
+
+        emit!(RecoveryVotesPruned {
+            pruned_by: *signer,
+            pruned_voters,
+            remaining_votes,
+            threshold: dol_state.emergency_recovery_threshold,
+            recovery_in_progress,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Snapshot global governance state in one call (public access)
+    /// Mirrors `get_book` so clients don't need to decode DoLState themselves
+    pub fn get_state(ctx: Context<GetState>) -> Result<StateSnapshot> {
+        // Get the DoL state account
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        let (transfer_pending, pending_super_admin, transfer_initiated_at, transfer_timelock) =
+            dol_state.get_transfer_status();
+        let (recovery_in_progress, recovery_new_admin, recovery_votes, recovery_threshold) =
+            dol_state.get_emergency_recovery_status();
+
+        let snapshot = StateSnapshot {
+            super_admin: dol_state.super_admin,
+            book_count: dol_state.book_count,
+            version: dol_state.version,
+            is_paused: dol_state.is_paused(),
+            admin_count: dol_state.admins.len() as u8,
+            moderator_count: dol_state.moderators.len() as u8,
+            curator_count: dol_state.curators.len() as u8,
+            pending_super_admin,
+            transfer_confirmable_at: if transfer_pending {
+                transfer_initiated_at + transfer_timelock
+            } else {
+                0
+            },
+            recovery_new_admin,
+            recovery_vote_count: recovery_votes.len() as u8,
+            recovery_threshold,
+        };
+
+        // Print the state details
+        msg!("DoL State:");
+        msg!("- Super Admin: {:?}", snapshot.super_admin);
+        msg!("- Version: {}", snapshot.version);
+        msg!("- Book Count: {}", snapshot.book_count);
+        msg!("- Paused: {}", snapshot.is_paused);
+        msg!(
+            "- Roles: {} admins, {} moderators, {} curators",
+            snapshot.admin_count,
+            snapshot.moderator_count,
+            snapshot.curator_count
+        );
+        if let Some(pending) = snapshot.pending_super_admin {
+            msg!(
+                "- Pending Transfer: {:?} (confirmable at {})",
+                pending,
+                snapshot.transfer_confirmable_at
+            );
+        } else {
+            msg!("- Pending Transfer: None");
+        }
+        if recovery_in_progress {
+            msg!(
+                "- Emergency Recovery: {:?} ({}/{} votes)",
+                snapshot.recovery_new_admin,
+                snapshot.recovery_vote_count,
+                snapshot.recovery_threshold
+            );
+        } else {
+            msg!(
+                "- Emergency Recovery: None (threshold {})",
+                snapshot.recovery_threshold
+            );
+        }
+        Ok(snapshot)
+    }
+
+    /// Report whether emergency recovery quorum is currently satisfiable (public access)
+    /// Lets operators detect an under-staffed admin set before a recovery is needed
+    pub fn recovery_feasible(ctx: Context<GetState>) -> Result<RecoveryFeasibility> {
+        // Get the DoL state account
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        let feasibility = RecoveryFeasibility {
+            feasible: dol_state.is_recovery_feasible(),
+            admin_count: dol_state.admins.len() as u8,
+            threshold: dol_state.emergency_recovery_threshold,
+        };
+
+        msg!(
+            "Recovery feasible: {} ({} admins, threshold {})",
+            feasibility.feasible,
+            feasibility.admin_count,
+            feasibility.threshold
+        );
+        Ok(feasibility)
+    }
+
+    /// Report which current admins have yet to vote on the in-progress recovery (public access)
+    /// Also returns how many more votes are needed to reach the threshold
+    pub fn recovery_pending_voters(ctx: Context<GetState>) -> Result<RecoveryPendingVoters> {
+        let dol_state: &Account<'_, DoLState> = &ctx.accounts.dol_state;
+
+        require!(
+            dol_state.emergency_recovery_new_admin.is_some(),
+            DoLError::NoEmergencyRecoveryInProgress
+        );
+
+        // Current admins who haven't voted yet
+        let pending_voters: Vec<Pubkey> = dol_state
+            .admins
+            .iter()
+            .filter(|admin| !dol_state.emergency_recovery_votes.contains(admin))
+            .copied()
+            .collect();
+
+        // Mirrors the execution check in vote_emergency_recovery
+        let votes_needed: u8 = (dol_state.emergency_recovery_threshold as usize)
+            .saturating_sub(dol_state.emergency_recovery_votes.len())
+            as u8;
+
+        msg!(
+            "Recovery pending voters: {:?} ({} more votes needed)",
+            pending_voters,
+            votes_needed
+        );
+        Ok(RecoveryPendingVoters {
+            pending_voters,
+            votes_needed,
+        })
+    }
+
+    /// Return the canonical DoLState PDA and bump (public access)
+    /// Single on-chain source of truth for SDKs deriving `[b"dol_state"]`
+    pub fn derive_state_address(ctx: Context<GetState>) -> Result<StateAddress> {
+        // The seeds constraint guarantees this is the canonical PDA
+        let state_address = StateAddress {
+            address: ctx.accounts.dol_state.key(),
+            bump: ctx.accounts.dol_state.bump,
+        };
+
+        msg!(
+            "DoL state address: {:?} (bump {})",
+            state_address.address,
+            state_address.bump
+        );
+        Ok(state_address)
+    }
+
+    /// Pause program operations (super admin only)
+    /// Emergency stop mechanism for security incidents
+    pub fn pause_program(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Prevent rapid pause/unpause cycling
+        let current_time: i64 = Clock::get()?.unix_timestamp;
+        validate_pause_toggle_cooldown(dol_state, current_time)?;
+
+        // Pause the program
+        dol_state.set_paused(true);
+        dol_state.last_pause_change_at = current_time;
+        msg!("Program paused by super admin: {:?}", signer);
+        emit!(ProgramPaused {
+            paused_by: *signer,
+            timestamp: current_time,
+        });
+        Ok(())
+    }
+
+    /// Unpause program operations (super admin only)
+    /// Resume normal operations after resolving issues
+    pub fn unpause_program(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Prevent rapid pause/unpause cycling
+        let current_time: i64 = Clock::get()?.unix_timestamp;
+        validate_pause_toggle_cooldown(dol_state, current_time)?;
+
+        // Unpause the program
+        dol_state.set_paused(false);
+        dol_state.last_pause_change_at = current_time;
+        msg!("Program unpaused by super admin: {:?}", signer);
+        emit!(ProgramUnpaused {
+            unpaused_by: *signer,
+            forced: false,
+            timestamp: current_time,
+        });
+        Ok(())
+    }
+
+    /// Pause or resume individual operation categories (super admin only)
+    /// `flags_mask` replaces the category bits; the global pause bit is left to pause/unpause
+    pub fn set_pause_flags(ctx: Context<ManageAdmin>, flags_mask: u8) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Only category bits may be set here
+        const CATEGORY_MASK: u8 = PAUSE_FLAG_BOOKS | PAUSE_FLAG_CARDS;
+        require!(
+            flags_mask & !CATEGORY_MASK == 0,
+            DoLError::InvalidPauseFlags
+        );
+
+        dol_state.flags = (dol_state.flags & !CATEGORY_MASK) | flags_mask;
+        msg!(
+            "Pause flags set by super admin: {:?} (books paused: {}, cards paused: {})",
+            signer,
+            flags_mask & PAUSE_FLAG_BOOKS != 0,
+            flags_mask & PAUSE_FLAG_CARDS != 0
+        );
+        emit!(PauseFlagsChanged {
+            flags: dol_state.flags,
+            changed_by: *signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Clear a stuck pause with approval from a majority of admins (admin only)
+    /// Additional approving admins sign the transaction and are passed as remaining accounts
+    pub fn force_unpause(ctx: Context<ManageAdmin>) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Only admins can force an unpause
+        require!(
+            dol_state.is_admin(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        require!(dol_state.flags & 1 != 0, DoLError::ProgramNotPaused);
+
+        // Count distinct admin signers, including the caller
+        let mut approvals: Vec<Pubkey> = vec![*signer];
+        for account in ctx.remaining_accounts.iter() {
+            if account.is_signer
+                && dol_state.is_admin(account.key)
+                && !approvals.contains(account.key)
+            {
+                approvals.push(*account.key);
+            }
+        }
+        require!(
+            approvals.len() > dol_state.admins.len() / 2,
+            DoLError::InsufficientUnpauseApprovals
+        );
+
+        dol_state.set_paused(false);
+        dol_state.last_pause_change_at = Clock::get()?.unix_timestamp;
+
+        msg!("SECURITY_EVENT: Program force-unpaused by admin majority");
+        msg!(
+            "  - Approvals: {}/{}",
+            approvals.len(),
+            dol_state.admins.len()
+        );
+        msg!("  - Approving admins: {:?}", approvals);
+        emit!(ProgramUnpaused {
+            unpaused_by: *signer,
+            forced: true,
+            timestamp: dol_state.last_pause_change_at,
+        });
+        Ok(())
+    }
+
+    /// Set how long a pause can last before it lapses automatically (super admin only)
+    /// Liveness safeguard against a program left paused forever; 0 disables auto-unpause
+    pub fn set_max_pause_duration(ctx: Context<ManageAdmin>, duration: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        require!(
+            (0..=MAX_PAUSE_DURATION).contains(&duration),
+            DoLError::InvalidPauseDuration
+        );
+
+        let previous: i64 = dol_state.max_pause_duration;
+        dol_state.max_pause_duration = duration;
+        msg!(
+            "Max pause duration changed: {} -> {} seconds by super admin: {:?}",
+            previous,
+            duration,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Set the minimum seconds between pause/unpause toggles (super admin only)
+    /// Stabilizes the pause flag for integrators; 0 disables the cooldown
+    pub fn set_pause_toggle_cooldown(ctx: Context<ManageAdmin>, cooldown: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        // Keep the cooldown short so a pause can't be locked in for long
+        require!(
+            (0..=MAX_PAUSE_TOGGLE_COOLDOWN).contains(&cooldown),
+            DoLError::InvalidCooldown
+        );
+
+        let previous: i64 = dol_state.pause_toggle_cooldown;
+        dol_state.pause_toggle_cooldown = cooldown;
+        msg!(
+            "Pause toggle cooldown changed: {} -> {} seconds by super admin: {:?}",
+            previous,
+            cooldown,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Set the grace window before newly added books become readable (admin only)
+    /// Moderators bypass the window so they can review fresh additions
+    pub fn set_book_visibility_delay(ctx: Context<ManageAdmin>, delay: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user has admin privileges
+        require!(
+            dol_state.has_admin_privileges(signer),
+            DoLError::InsufficientPermissions
+        );
+
+        require!(
+            (0..=MAX_BOOK_VISIBILITY_DELAY).contains(&delay),
+            DoLError::InvalidVisibilityDelay
+        );
+
+        let previous: i64 = dol_state.book_visibility_delay;
+        dol_state.book_visibility_delay = delay;
+        msg!(
+            "Book visibility delay changed: {} -> {} seconds by {:?}",
+            previous,
+            delay,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Replace the curator and moderator permission bits (super admin only)
+    pub fn set_permissions(ctx: Context<ManageAdmin>, permissions: u32) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        require!(
+            permissions & !PERMISSION_MASK == 0,
+            DoLError::InvalidPermissions
+        );
+
+        let old_permissions: u32 = dol_state.permissions;
+        dol_state.permissions = permissions;
+
+        // Enhanced audit logging
+        msg!("SECURITY_EVENT: Role permissions changed");
+        msg!("  - Changed by: {:?}", signer);
+        msg!("  - Old permissions: {:#09b}", old_permissions);
+        msg!("  - New permissions: {:#09b}", permissions);
+        msg!("  - Changed at: {}", Clock::get()?.unix_timestamp);
+        Ok(())
+    }
+
+    /// Cap the number of books in the catalog (super admin only)
+    /// 0 removes the cap; a cap below the current book_count only blocks new additions
+    pub fn set_max_books(ctx: Context<ManageAdmin>, max_books: u64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        let previous: u64 = dol_state.max_books;
+        dol_state.max_books = max_books;
+        msg!(
+            "Catalog size cap changed: {} -> {} books by super admin: {:?}",
+            previous,
+            max_books,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Require library cards to be a minimum age before rating, reviewing, or reporting (super admin only)
+    /// Makes minting throwaway cards for spam slower; 0 disables the check
+    pub fn set_min_card_age(ctx: Context<ManageAdmin>, min_card_age_seconds: i64) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        require!(
+            (0..=MAX_MIN_CARD_AGE).contains(&min_card_age_seconds),
+            DoLError::InvalidCardAge
+        );
+
+        let previous: i64 = dol_state.min_card_age_seconds;
+        dol_state.min_card_age_seconds = min_card_age_seconds;
+        msg!(
+            "Minimum card age changed: {} -> {} seconds by super admin: {:?}",
+            previous,
+            min_card_age_seconds,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Allow or forbid card holders from changing an existing rating (super admin only)
+    pub fn set_rating_update_policy(ctx: Context<ManageAdmin>, allow: bool) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        let previous: bool = dol_state.allow_rating_update;
+        dol_state.allow_rating_update = allow;
+        msg!(
+            "Rating update policy changed: {} -> {} by super admin: {:?}",
+            previous,
+            allow,
+            signer
+        );
+        Ok(())
+    }
+
+    /// Set the language applied to books added without one (super admin only)
+    /// Books can still pass their own language code
+    pub fn set_default_language(ctx: Context<ManageAdmin>, language: [u8; 2]) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        validate_language(&language)?;
+
+        let previous: [u8; 2] = dol_state.default_language;
+        dol_state.default_language = language;
+        msg!(
+            "Default language changed: {} -> {} by super admin: {:?}",
+            String::from_utf8_lossy(&previous),
+            String::from_utf8_lossy(&language),
+            signer
+        );
+        Ok(())
+    }
+
+    /// Require a book's cover hash to differ from its content hash (super admin only)
+    /// Off by default, since a cover can legitimately point at the content
+    pub fn set_distinct_cover_policy(ctx: Context<ManageAdmin>, enabled: bool) -> Result<()> {
+        // Get the DoL state account
+        let dol_state: &mut Account<'_, DoLState> = &mut ctx.accounts.dol_state;
+        // Get the signer
+        let signer: &Pubkey = &ctx.accounts.authority.key();
+
+        // Check if user is super admin
+        require!(dol_state.is_super_admin(signer), DoLError::OnlySuperAdmin);
+
+        let previous: bool = dol_state.distinct_cover;
+        dol_state.distinct_cover = enabled;
+        msg!(
+            "Distinct cover policy changed: {} -> {} by super admin: {:?}",
+            previous,
+            enabled,
+            signer
+        );
+        Ok(())
+    }
+}
+
+// Account structures
+/// Global program state - tracks admin authorities and book catalog size
+#[account]
+pub struct DoLState {
+    pub super_admin: Pubkey,     // Current super admin with full control
+    pub admins: Vec<Pubkey>,     // Library admins (can add/remove books, manage roles)
+    pub moderators: Vec<Pubkey>, // Moderators (can flag content, limited admin powers)
+    pub curators: Vec<Pubkey>,   // Curators (can add books but not remove)
+    pub book_count: u64,         // Total books added (for analytics and metrics)
+    pub version: u8,             // Program version for future upgrades
+    pub flags: u8, // Bit flags: bit 0 = is_paused (overrides all), bit 1 = books_paused, bit 2 = cards_paused
+    pub bump: u8,  // PDA bump seed
+    // Super admin transfer security fields
+    pub pending_super_admin: Option<Pubkey>, // Pending new super admin (if transfer initiated)
+    pub transfer_initiated_at: i64,          // Timestamp when transfer was initiated
+    pub transfer_timelock: i64, // Required delay before transfer can be confirmed (default: 7 days)
+    // Emergency recovery fields
+    pub emergency_recovery_threshold: u8, // Number of admin signatures required for emergency recovery
+    pub emergency_recovery_initiated_at: i64, // Timestamp when emergency recovery was initiated
+    pub emergency_recovery_votes: Vec<Pubkey>, // Admins who have voted for emergency recovery
+    pub emergency_recovery_new_admin: Option<Pubkey>, // Proposed new super admin for recovery
+    // Rate limiting fields
+    pub last_book_addition: i64,     // Timestamp of last book addition
+    pub books_added_today: u16,      // Number of books added in current day
+    pub last_book_addition_day: i64, // Day (unix timestamp / 86400) of last book count reset
+    // Rating policy fields
+    pub allow_rating_update: bool, // Whether card holders may change an existing rating
+    // Pause toggle fields
+    pub pause_toggle_cooldown: i64, // Minimum seconds between pause/unpause toggles (0 = none)
+    pub last_pause_change_at: i64, // Timestamp of the last pause/unpause toggle (pause start while paused)
+    pub max_pause_duration: i64,   // Seconds after which a pause lapses automatically (0 = never)
+    // Moderation fields
+    pub book_visibility_delay: i64, // Seconds after addition before readers can see a book (0 = immediate)
+    // Membership fields
+    pub membership_number: u64, // Last membership number issued (0 = no cards minted yet)
+    // Treasury fields
+    pub fee_lamports: u64, // Fee charged by mint_library_card, paid into the treasury (0 = free)
+    // Emergency recovery timelock fields
+    pub emergency_recovery_timelock: i64, // Seconds after initiation before a recovery can execute
+    // Super admin renunciation fields
+    pub renounce_requested_at: i64, // When renounce_super_admin was armed (0 = not armed)
+    // Catalog size fields
+    pub max_books: u64, // Ceiling on book_count enforced when adding books (0 = unlimited)
+    // Card tier fields
+    pub upgrade_fee_lamports: u64, // Fee charged by upgrade_card, paid into the treasury (0 = free)
+    // Role permission fields
+    pub permissions: u32, // Role permission bits granted to curators and moderators
+    // Sybil resistance fields
+    pub min_card_age_seconds: i64, // Card age required to rate, review, or report (0 = none)
+    // Sequential index fields
+    pub next_book_seq: u64, // Sequence number the next added book receives (never reused)
+    // Default language fields
+    pub default_language: [u8; 2], // ISO 639-1 code applied to books added without a language
+    // Cover policy fields
+    pub distinct_cover: bool, // Whether a book's cover hash must differ from its content hash
+}
+
+/// Catalog genres, stored as a single byte
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Genre {
+    Fiction,
+    NonFiction,
+    Science,
+    History,
+    Biography,
+    Poetry,
+    Children,
+    Reference,
+    Other, // Named by the book's genre_label
+}
+
+/// Individual book record with metadata and IPFS content reference
+#[account]
+pub struct Book {
+    pub id: [u8; 16],               // Unique book ID (UUID generated by client)
+    pub title: String,              // Book title
+    pub authors: Vec<String>,       // Author names (the first is the primary author)
+    pub ipfs_hash: String,          // IPFS hash pointing to book content
+    pub metadata_ipfs_hash: String, // IPFS hash of an extended metadata JSON document (empty = none)
+    pub cover_ipfs_hash: String,    // IPFS hash of a cover thumbnail (empty = no cover)
+    pub genre: Genre,               // Book genre/category
+    pub genre_label: String,        // Free-form genre name, only set for Genre::Other
+    pub content_rating: u8,         // Age rating (0 unrated, 1 everyone, 2 teen, 3 mature)
+    pub page_count: u32,            // Number of pages (0 if unknown)
+    pub word_count: u32,            // Number of words (0 if unknown)
+    pub oclc: u64,                  // OCLC (WorldCat) number (0 = none)
+    pub lccn: [u8; 12],             // Library of Congress Control Number, zero-padded (zero = none)
+    pub publication_year: u16,      // Publication year (optional, 0 if unknown)
+    pub added_timestamp: i64,       // When book was added to catalog
+    pub added_by: Pubkey,           // Who added this book (for audit trail)
+    pub bump: u8,                   // PDA bump seed
+    pub read_count: u64,            // Counted reads (first read per user, then once per cooldown)
+    pub rating_sum: u64,            // Sum of all current ratings
+    pub rating_count: u32,          // Number of card holders who rated this book
+    pub rating_buckets: [u32; 5],   // Current ratings per star (index 0 = 1 star)
+    pub attestation_count: u32,     // Number of curators who attested they reviewed this book
+    pub review_count: u32,          // Number of written reader reviews
+    pub superseded_by: [u8; 16],    // ID of the newer edition replacing this book (zero = none)
+    pub last_updated_by: Pubkey,    // Who last updated this book (added_by until the first edit)
+    pub last_updated_at: i64, // When this book was last updated (added_timestamp until the first edit)
+    pub flags: u8,            // Status bits (bit 0 = archived, 1 = flagged, 2 = under review)
+    pub language: [u8; 2],    // ISO 639-1 language code, e.g. b"en" (zero = default)
+    pub isbn: Option<[u8; 13]>, // ISBN-13 as ASCII digits (None = no ISBN)
+    pub description: String,  // Short blurb shown in the catalog (empty = none)
+    pub seq: u64,             // Position in the sequential index, assigned at add time
+    pub title_key: [u8; 32],  // Seed of the BookTitleIndex the book holds (zero = none)
+    pub reserved: [u8; 1],    // Reserved space for future features
+}
+
+/// Library Card NFT that grants reading access to all books
+#[account]
+pub struct LibraryCard {
+    pub owner: Pubkey,          // Card holder's wallet address
+    pub mint_timestamp: i64,    // When card was minted
+    pub bump: u8,               // PDA bump seed
+    pub membership_number: u64, // Sequential number assigned at mint
+    pub tier: u8,               // CardTier as a byte (0 = standard)
+    pub transferred_at: i64,    // When the card last moved wallets (0 = never)
+    pub progress_count: u16,    // Reading progress records saved by this wallet
+    pub review_count: u16,      // Reviews this wallet has posted and not deleted
+    pub rating_count: u16,      // Books this wallet has rated
+    pub reserved: [u8; 25],     // Reserved space for future features
+}
+
+/// Library card access levels, stored on the card as a byte
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CardTier {
+    Standard,
+    Premium,
+}
+
+/// Per-user read receipt for a book, seeded by reader and book ID
+#[account]
+pub struct ReadReceipt {
+    pub reader: Pubkey,     // Card holder who read the book
+    pub book_id: [u8; 16],  // Book that was read
+    pub first_read_at: i64, // Timestamp of the first recorded read
+    pub last_read_at: i64,  // Timestamp of the most recent recorded read
+    pub bump: u8,           // PDA bump seed
+}
+
+/// Per-user reading position in a book, seeded by reader and book ID
+#[account]
+pub struct ReadingProgress {
+    pub reader: Pubkey,    // Card holder whose progress this is
+    pub book_id: [u8; 16], // Book being read
+    pub percent: u8,       // How far through the book (0-100)
+    pub updated_at: i64,   // When the progress was last saved
+    pub bump: u8,          // PDA bump seed
+}
+
+/// Bounded index of the most recent books added by one authority
+#[account]
+pub struct AuthorBooks {
+    pub authority: Pubkey, // Super admin, admin, or curator who added the books
+    pub book_ids: Vec<[u8; 16]>, // Most recent book IDs, oldest first (max MAX_AUTHOR_BOOKS)
+    pub bump: u8,          // PDA bump seed
+}
+
+/// Catalog-wide list of active book IDs so clients can page the catalog from one account
+/// A single bounded account (MAX_CATALOG_BOOKS) rather than shards; archived and removed
+/// books are dropped, and add_book fails with CatalogFull once it is full
+#[account]
+pub struct BookIndex {
+    pub book_ids: Vec<[u8; 16]>, // Active book IDs, oldest first
+    pub bump: u8,                // PDA bump seed
+}
+
+/// Book IDs under one primary author, seeded by `author_index_key`
+/// Keyed by the book's current primary author; update_book moves entries when it changes.
+/// One bounded account per author (MAX_AUTHOR_INDEX_BOOKS) and add_book fails with IndexFull
+/// once it is full. Sharding would add a page number to the seeds, with new books going to
+/// the last page, without changing the entry layout.
+#[account]
+pub struct AuthorIndex {
+    pub author_key: [u8; 32],    // author_index_key of the primary author
+    pub book_ids: Vec<[u8; 16]>, // Book IDs, oldest first
+    pub bump: u8,                // PDA bump seed
+}
+
+/// Book IDs in one genre, seeded by [b"genre_idx", genre as u8]
+/// Same keying, bound (MAX_GENRE_INDEX_BOOKS) and sharding path as AuthorIndex
+#[account]
+pub struct GenreIndex {
+    pub genre: Genre,            // Genre the books were added under
+    pub book_ids: Vec<[u8; 16]>, // Book IDs, oldest first
+    pub bump: u8,                // PDA bump seed
+}
+
+/// Maps a sequence number to a book, seeded by [b"seq", seq as little-endian u64]
+/// Clients page the catalog by walking 0..next_book_seq; a missing index or a closed
+/// book is a removed entry and is skipped
+#[account]
+pub struct SeqIndex {
+    pub seq: u64,          // Sequence number assigned when the book was added
+    pub book_id: [u8; 16], // Book at this position
+    pub bump: u8,          // PDA bump seed
+}
+
+/// Claims a normalized title + author for one book, seeded by `title_index_key`
+#[account]
+pub struct BookTitleIndex {
+    pub book_id: [u8; 16], // Book that holds this title + author
+    pub bump: u8,          // PDA bump seed
+}
+
+/// Contribution record for one book-adding authority, seeded by that authority
+#[account]
+pub struct CuratorStats {
+    pub curator: Pubkey,     // Authority credited with the additions
+    pub books_added: u64,    // Books added and still in the catalog
+    pub bump: u8,            // PDA bump seed
+    pub window_start: i64,   // Start of the current rate limit window
+    pub adds_in_window: u16, // Books added since window_start
+    pub reserved: [u8; 6],   // Reserved space for future features
+}
+
+/// A curator's attestation that they reviewed a book, seeded by book ID and curator
+#[account]
+pub struct ReviewAttestation {
+    pub book_id: [u8; 16], // Book that was reviewed
+    pub curator: Pubkey,   // Curator who reviewed it
+    pub reviewed_at: i64,  // When the review was attested
+    pub bump: u8,          // PDA bump seed
+}
+
+/// A card holder's written review of a book, seeded by book and reviewer
+#[account]
+pub struct Review {
+    pub reviewer: Pubkey,  // Card holder who wrote the review
+    pub book_id: [u8; 16], // Book that was reviewed
+    pub text: String, // Review text (up to MAX_REVIEW_TEXT_LEN characters, MAX_REVIEW_TEXT_BYTES bytes)
+    pub stars: u8,    // Star score attached to the review (1-5)
+    pub created_at: i64, // When the review was first posted
+    pub bump: u8,     // PDA bump seed
+}
+
+/// A curator's pending catalog addition, seeded by the proposed book ID
+#[account]
+pub struct BookProposal {
+    pub id: [u8; 16],               // UUID the book will be created under
+    pub proposer: Pubkey,           // Who proposed the book (credited on approval)
+    pub title: String,              // Book title
+    pub authors: Vec<String>,       // Author names (the first is the primary author)
+    pub ipfs_hash: String,          // IPFS hash of the book content
+    pub metadata_ipfs_hash: String, // IPFS hash of the metadata document ("" if none)
+    pub cover_ipfs_hash: String,    // IPFS hash of the cover image ("" if none)
+    pub genre: Genre,               // Catalog genre
+    pub genre_label: String,        // Genre name when genre is Other
+    pub content_rating: u8,         // One of the CONTENT_RATING_* constants
+    pub page_count: u32,            // Page count (0 if unknown)
+    pub word_count: u32,            // Word count (0 if unknown)
+    pub oclc: u64,                  // OCLC number (0 if unknown)
+    pub lccn: [u8; 12],             // LCCN (zero-padded, all zeros if unknown)
+    pub publication_year: u16,      // Publication year (0 if unknown)
+    pub language: [u8; 2],          // ISO 639-1 language code
+    pub isbn: Option<[u8; 13]>,     // ISBN-13 as ASCII digits (None = no ISBN)
+    pub proposed_at: i64,           // When the proposal was submitted
+    pub bump: u8,                   // PDA bump seed
+}
+
+/// A card holder's report about a book, seeded by book account and reporter
+#[account]
+pub struct BookReport {
+    pub reporter: Pubkey, // Card holder who filed the report
+    pub book: Pubkey,     // Book account that was reported
+    pub reason_code: u8,  // One of the REPORT_REASON_* constants
+    pub details: String,  // Free-form details (up to MAX_REPORT_DETAILS_LEN characters)
+    pub created_at: i64,  // When the report was filed
+    pub bump: u8,         // PDA bump seed
+}
+
+/// A card holder's named group of books, seeded by creator and name hash
+#[account]
+pub struct Collection {
+    pub creator: Pubkey,         // Card holder who owns the collection
+    pub name_hash: [u8; 32],     // SHA-256 of the name (PDA seed)
+    pub name: String,            // Collection name (up to MAX_COLLECTION_NAME_LEN characters)
+    pub description: String,     // Optional description (empty = none)
+    pub book_ids: Vec<[u8; 16]>, // Collected book IDs in insertion order (up to MAX_COLLECTION_BOOKS)
+    pub created_at: i64,         // When the collection was created
+    pub bump: u8,                // PDA bump seed
+}
+
+/// A reader's highlight or note on a book, seeded by reader, book, and slot index
+#[account]
+pub struct Annotation {
+    pub reader: Pubkey,    // Card holder who made the annotation
+    pub book_id: [u8; 16], // Book that was annotated
+    pub index: u16,        // Slot number (below MAX_ANNOTATIONS_PER_BOOK)
+    pub start_offset: u32, // First byte of the annotated range in the book content
+    pub end_offset: u32,   // Byte just past the annotated range
+    pub note: String, // Optional note (up to MAX_ANNOTATION_NOTE_LEN characters, empty = highlight)
+    pub created_at: i64, // When the annotation was created
+    pub updated_at: i64, // When the annotation was last changed
+    pub bump: u8,     // PDA bump seed
+}
+
+/// A member's list of books to read later, seeded by owner
+#[account]
+pub struct Wishlist {
+    pub owner: Pubkey,           // Card holder who owns the wishlist
+    pub book_ids: Vec<[u8; 16]>, // Wishlisted book IDs in insertion order (up to MAX_WISHLIST_BOOKS)
+    pub bump: u8,                // PDA bump seed
+}
+
+/// A card holder's rating of a book, seeded by book and rater
+#[account]
+pub struct RatingReceipt {
+    pub rater: Pubkey,     // Card holder who rated the book
+    pub book_id: [u8; 16], // Book that was rated
+    pub rating: u8,        // Current rating (1-5)
+    pub rated_at: i64,     // Timestamp of the latest rating
+    pub bump: u8,          // PDA bump seed
+}
+
+// Return data structures
+/// Governance state snapshot returned by `get_state`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StateSnapshot {
+    pub super_admin: Pubkey,
+    pub book_count: u64,
+    pub version: u8,
+    pub is_paused: bool, // Effective pause state (accounts for an expired pause)
+    pub admin_count: u8,
+    pub moderator_count: u8,
+    pub curator_count: u8,
+    pub pending_super_admin: Option<Pubkey>, // Incoming super admin, if a transfer is pending
+    pub transfer_confirmable_at: i64,        // When the pending transfer can be claimed (0 if none)
+    pub recovery_new_admin: Option<Pubkey>,  // Proposed super admin, if a recovery is in progress
+    pub recovery_vote_count: u8,             // Votes cast for the in-progress recovery
+    pub recovery_threshold: u8,              // Votes required for emergency recovery
+}
+
+/// Recovery quorum snapshot returned by `recovery_feasible`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecoveryFeasibility {
+    pub feasible: bool,  // Whether admins.len() >= emergency_recovery_threshold
+    pub admin_count: u8, // Current number of admins
+    pub threshold: u8,   // Votes required for emergency recovery
+}
+
+/// Outstanding recovery votes returned by `recovery_pending_voters`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecoveryPendingVoters {
+    pub pending_voters: Vec<Pubkey>, // Current admins not in emergency_recovery_votes
+    pub votes_needed: u8,            // Votes still required to reach the threshold
+}
+
+/// Canonical DoLState PDA returned by `derive_state_address`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StateAddress {
+    pub address: Pubkey, // PDA derived from [b"dol_state"]
+    pub bump: u8,        // Canonical bump stored in DoLState
+}
+
+/// Book summary returned by `get_book`, readable by CPI callers via `get_return_data`
+/// Borsh layout, in order: id [u8; 16], title (u32 length + UTF-8 bytes),
+/// ipfs_hash (u32 length + UTF-8 bytes), genre (u8 variant index),
+/// publication_year u16 (0 = unknown), page_count u32, word_count u32,
+/// est_reading_minutes Option<u32> (u8 tag, then u32 if present), under_review bool (u8),
+/// last_updated_at i64, last_updated_by [u8; 32], archived bool (u8), superseded_by [u8; 16]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BookSummary {
+    pub id: [u8; 16],
+    pub title: String,
+    pub ipfs_hash: String,
+    pub genre: Genre,
+    pub publication_year: u16,
+    pub page_count: u32,
+    pub word_count: u32,
+    pub est_reading_minutes: Option<u32>, // At DEFAULT_READING_WPM; None if the word count is unknown
+    pub under_review: bool,               // Hidden from readers while a moderator reviews it
+    pub last_updated_at: i64,             // added_timestamp until the first update_book
+    pub last_updated_by: Pubkey,          // added_by until the first update_book
+    pub archived: bool,                   // Hidden from the catalog, record kept for provenance
+    pub superseded_by: [u8; 16],          // ID of the newer edition (zero = none)
+}
+
+/// Consolidated user footprint returned by `get_user_profile`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserProfile {
+    pub user: Pubkey,
+    pub has_card: bool,
+    pub card_minted_at: i64, // 0 if the user has no card
+    pub books_added: u64,    // Books the user added that are still in the catalog (CuratorStats)
+    pub wishlist_count: u32, // Books on the user's wishlist (0 if none)
+    pub progress_count: u16, // Books with saved reading progress (0 if no card)
+    pub review_count: u16,   // Reviews posted and not deleted (0 if no card)
+    pub rating_count: u16,   // Books rated (0 if no card)
+}
+
+/// Book provenance returned by `get_book_audit`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BookAudit {
+    pub id: [u8; 16],
+    pub added_by: Pubkey,
+    pub added_timestamp: i64,
+    pub last_updated_by: Pubkey, // Equals added_by if never updated
+    pub last_updated_at: i64,    // Equals added_timestamp if never updated
+    pub attestation_count: u32,
+    pub superseded_by: [u8; 16], // Zero if not superseded
+    pub flags: u8,               // BOOK_FLAG_ARCHIVED | BOOK_FLAG_FLAGGED | BOOK_FLAG_UNDER_REVIEW
+}
+
+/// Star histogram returned by `get_rating_distribution`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RatingDistribution {
+    pub book_id: [u8; 16],
+    pub buckets: [u32; 5], // Ratings per star (index 0 = 1 star)
+    pub rating_count: u32,
+}
+
+/// Read receipt details returned by `get_read_receipt`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReadReceiptInfo {
+    pub reader: Pubkey,
+    pub book_id: [u8; 16],
+    pub first_read_at: i64,
+    pub last_read_at: i64,
+}
+
+// Events
+/// Emitted when a book is added to the catalog
+#[event]
+pub struct BookAdded {
+    pub id: [u8; 16],
+    pub title: String,
+    pub authors: Vec<String>,
+    pub genre: Genre,
+    pub added_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a book is proposed for admin review
+#[event]
+pub struct BookProposed {
+    pub id: [u8; 16],
+    pub title: String,
+    pub authors: Vec<String>,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an admin rejects a book proposal
+#[event]
+pub struct ProposalRejected {
+    pub id: [u8; 16],
+    pub proposer: Pubkey,
+    pub rejected_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a book's metadata is updated
+#[event]
+pub struct BookUpdated {
+    pub id: [u8; 16],
+    pub title: String,
+    pub authors: Vec<String>,
+    pub genre: Genre,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a book is archived (its account is kept)
+#[event]
+pub struct BookArchived {
+    pub id: [u8; 16],
+    pub archived_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a moderator puts a book under review or clears it
+#[event]
+pub struct BookReviewFlagChanged {
+    pub id: [u8; 16],
+    pub under_review: bool,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the super admin moves a book to a new ID
+#[event]
+pub struct BookIdMigrated {
+    pub old_id: [u8; 16],
+    pub new_id: [u8; 16],
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a book is removed (its account is closed)
+#[event]
+pub struct BookRemoved {
+    pub id: [u8; 16],
+    pub title: String,
+    pub authors: Vec<String>,
+    pub genre: Genre,
+    pub added_by: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when purge_curator_books closes a compromised curator's books
+#[event]
+pub struct CuratorBooksPurged {
+    pub curator: Pubkey,
+    pub removed: u64, // Books closed in this batch
+    pub skipped: u64, // Books in the batch added by someone else
+    pub purged_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A wallet's highest role, returned by `get_user_role`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UserRole {
+    SuperAdmin,
+    Admin,
+    Moderator,
+    Curator,
+    Member, // Holds a library card but no governance role
+    None,
+}
+
+/// Role categories reported in `RoleChanged`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoleKind {
+    Admin,
+    Curator,
+    Moderator,
+}
+
+/// Emitted when the super admin starts a timelocked super admin transfer
+#[event]
+pub struct SuperAdminTransferInitiated {
+    pub super_admin: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub confirmable_at: i64, // Earliest time the new super admin can confirm
+    pub timestamp: i64,
+}
+
+/// Emitted when the pending super admin claims the role
+#[event]
+pub struct SuperAdminTransferConfirmed {
+    pub old_super_admin: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub initiated_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the super admin cancels a pending transfer
+#[event]
+pub struct SuperAdminTransferCancelled {
+    pub cancelled_by: Pubkey,
+    pub cancelled_super_admin: Pubkey, // The pending super admin that was dropped
+    pub initiated_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an admin starts an emergency recovery (counts as the first vote)
+#[event]
+pub struct EmergencyRecoveryInitiated {
+    pub initiated_by: Pubkey,
+    pub super_admin: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub threshold: u8,
+    pub executable_at: i64, // Earliest execution time once the threshold is met
+    pub timestamp: i64,
+}
+
+/// Emitted for each additional emergency recovery vote
+#[event]
+pub struct EmergencyRecoveryVoted {
+    pub voter: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub votes: u8, // Total votes after this one
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an emergency recovery installs a new super admin
+#[event]
+pub struct EmergencyRecoveryExecuted {
+    pub executed_by: Pubkey,
+    pub old_super_admin: Pubkey,
+    pub new_super_admin: Pubkey,
+    pub initiated_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the super admin cancels an emergency recovery
+#[event]
+pub struct EmergencyRecoveryCancelled {
+    pub cancelled_by: Pubkey,
+    pub cancelled_super_admin: Pubkey, // The proposed super admin that was dropped
+    pub votes: u8,                     // Votes collected before cancellation
+    pub initiated_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the super admin pauses the whole program
+#[event]
+pub struct ProgramPaused {
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the program is unpaused, by the super admin or an admin majority
+#[event]
+pub struct ProgramUnpaused {
+    pub unpaused_by: Pubkey,
+    pub forced: bool, // true when cleared by force_unpause
+    pub timestamp: i64,
+}
+
+/// Emitted when the super admin changes the per-category pause bits
+#[event]
+pub struct PauseFlagsChanged {
+    pub flags: u8, // Full DoLState.flags after the change
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a card holder burns their library card
+#[event]
+pub struct LibraryCardBurned {
+    pub owner: Pubkey,
+    pub mint_timestamp: i64, // When the burned card was originally minted
+    pub timestamp: i64,
+}
+
+/// Emitted when a card holder moves their library card to another wallet
+#[event]
+pub struct LibraryCardTransferred {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub membership_number: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted for every newly minted library card, whichever instruction created it
+#[event]
+pub struct LibraryCardMinted {
+    pub owner: Pubkey,
+    pub membership_number: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted for each card created by `mint_cards_sponsored`
+#[event]
+pub struct LibraryCardSponsored {
+    pub owner: Pubkey,
+    pub sponsor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted on every admin, curator, or moderator grant or revocation
+#[event]
+pub struct RoleChanged {
+    pub member: Pubkey,
+    pub role: RoleKind,
+    pub added: bool, // true for a grant, false for a revocation
+    pub changed_by: Pubkey,
+    pub note: String, // Optional governance context (empty if none)
+    pub timestamp: i64,
+}
+
+/// Emitted when stale emergency recovery votes are pruned
+#[event]
+pub struct RecoveryVotesPruned {
+    pub pruned_by: Pubkey,
+    pub pruned_voters: Vec<Pubkey>,
+    pub remaining_votes: u8,
+    pub threshold: u8,
+    pub recovery_in_progress: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an annotation is created or updated, so reader apps can sync highlights
+#[event]
+pub struct AnnotationSaved {
+    pub reader: Pubkey,
+    pub book_id: [u8; 16],
+    pub index: u16,
+    pub start_offset: u32,
+    pub end_offset: u32,
+    pub note: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an annotation is deleted
+#[event]
+pub struct AnnotationDeleted {
+    pub reader: Pubkey,
+    pub book_id: [u8; 16],
+    pub index: u16,
+    pub timestamp: i64,
+}
+
+// Context structures
+/// Initialize the DoL program state account (super admin only)
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init_if_needed, // A repeat call fails with AlreadyInitialized instead of "already in use"
+        payer = super_admin,
+        space = DOL_STATE_SPACE,
+        seeds = [b"dol_state"],              // Global singleton PDA
+        bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mint a library card NFT for a user (one per wallet)
+#[derive(Accounts)]
+pub struct MintLibraryCard<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = user,
+        space = LIBRARY_CARD_SPACE,
+        seeds = [b"library_card", user.key().as_ref()],    // User-specific PDA
+        bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw collected card fees (super admin only)
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burn the signer's library card (card holder only)
+#[derive(Accounts)]
+pub struct BurnLibraryCard<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"library_card", user.key().as_ref()],
+        bump = library_card.bump,
+        constraint = library_card.owner == user.key() @ DoLError::NotCardOwner
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+/// Move the signer's library card to a new wallet (card holder only)
+/// `init` on the new PDA rejects a wallet that already holds a card
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferCard<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"library_card", user.key().as_ref()],
+        bump = library_card.bump,
+        constraint = library_card.owner == user.key() @ DoLError::NotCardOwner
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init,
+        payer = user,
+        space = LIBRARY_CARD_SPACE,
+        seeds = [b"library_card", new_owner.as_ref()],
+        bump
+    )]
+    pub new_library_card: Account<'info, LibraryCard>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade the signer's library card tier (card holder only)
+#[derive(Accounts)]
+pub struct UpgradeCard<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"library_card", user.key().as_ref()],
+        bump = library_card.bump,
+        constraint = library_card.owner == user.key() @ DoLError::NotCardOwner
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mint library cards for many users with an admin as payer
+#[derive(Accounts)]
+pub struct MintCardsSponsored<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize global state and mint the super admin's library card together
+#[derive(Accounts)]
+pub struct InitializeWithCard<'info> {
+    #[account(
+        init_if_needed, // A repeat call fails with AlreadyInitialized instead of "already in use"
+        payer = super_admin,
+        space = DOL_STATE_SPACE,
+        seeds = [b"dol_state"],
+        bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        init,
+        payer = super_admin,
+        space = LIBRARY_CARD_SPACE,
+        seeds = [b"library_card", super_admin.key().as_ref()],
+        bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Add a new book to the catalog (super admin, admin, or curator)
+#[derive(Accounts)]
+#[instruction(id: [u8; 16], title: String, authors: Vec<String>, ipfs_hash: String, genre: Genre, genre_label: String, metadata_ipfs_hash: String, content_rating: u8, page_count: u32, word_count: u32, oclc: u64, lccn: [u8; 12], publication_year: Option<u16>, cover_ipfs_hash: Option<String>, language: Option<[u8; 2]>, isbn: Option<[u8; 13]>, description: Option<String>)]
+pub struct AddBook<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        init,
+        payer = authority,
+        space = book_space(&title, &authors, &ipfs_hash, &metadata_ipfs_hash, cover_ipfs_hash.as_deref().unwrap_or_default(), &genre_label, description.as_deref().unwrap_or_default()),
+        seeds = [b"book", id.as_ref()],     // UUID-based PDA addressing
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", authority.key().as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    /// CHECK: Created by add_book at the title + author PDA; an existing index is rejected
+    #[account(mut)]
+    pub title_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the primary author's index PDA and created on first use
+    #[account(mut)]
+    pub author_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the genre's index PDA and created on first use
+    #[account(mut)]
+    pub genre_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the next sequence number's PDA and created here
+    #[account(mut)]
+    pub seq_index: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", authority.key().as_ref()],
+        bump
+    )]
+    pub curator_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a new book for review (super admin, admin, or curator)
+#[derive(Accounts)]
+#[instruction(id: [u8; 16], title: String, authors: Vec<String>, ipfs_hash: String, genre: Genre, genre_label: String, metadata_ipfs_hash: String, content_rating: u8, page_count: u32, word_count: u32, oclc: u64, lccn: [u8; 12], publication_year: Option<u16>, cover_ipfs_hash: Option<String>)]
+pub struct ProposeBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR + 16 + 32 + (4 + title.len()) + (4 + authors.iter().map(|author| 4 + author.len()).sum::<usize>()) + (4 + ipfs_hash.len()) + (4 + metadata_ipfs_hash.len()) + (4 + cover_ipfs_hash.as_ref().map_or(0, |hash| hash.len())) + 1 + (4 + genre_label.len()) + 1 + 4 + 4 + 8 + 12 + 2 + 2 + (1 + 13) + 8 + 1,
+        seeds = [b"proposal", id.as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, BookProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve a book proposal into the catalog (admin only)
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", proposal.id.as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, BookProposal>,
+    /// CHECK: Receives the rent refund; constrained to the proposal's author
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = book_space(&proposal.title, &proposal.authors, &proposal.ipfs_hash, &proposal.metadata_ipfs_hash, &proposal.cover_ipfs_hash, &proposal.genre_label, ""),
+        seeds = [b"book", proposal.id.as_ref()],
+        bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", proposal.proposer.as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    /// CHECK: Created by approve_proposal at the title + author PDA; an existing index is rejected
+    #[account(mut)]
+    pub title_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the proposed author's index PDA and created on first use
+    #[account(mut)]
+    pub author_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the genre's index PDA and created on first use
+    #[account(mut)]
+    pub genre_index: UncheckedAccount<'info>,
+    /// CHECK: Verified against the next sequence number's PDA and created here
+    #[account(mut)]
+    pub seq_index: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", proposal.proposer.as_ref()],
+        bump
+    )]
+    pub curator_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Reject a book proposal (admin only)
+#[derive(Accounts)]
+pub struct RejectProposal<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", proposal.id.as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, BookProposal>,
+    /// CHECK: Receives the rent refund; constrained to the proposal's author
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+/// Update book metadata (super admin, admin, or curator)
+#[derive(Accounts)]
+pub struct UpdateBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    pub authority: Signer<'info>,
+    /// Funds the rent when longer fields need more space or the book moves between indexes;
+    /// other updates can omit it
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    pub system_program: Option<Program<'info, System>>,
+    /// Title claim the book holds, released to the payer when the title or primary author changes
+    #[account(
+        mut,
+        seeds = [b"title_idx", book.title_key.as_ref()],
+        bump = title_index.bump,
+        constraint = title_index.book_id == book.id @ DoLError::TitleIndexMismatch
+    )]
+    pub title_index: Option<Account<'info, BookTitleIndex>>,
+    /// CHECK: Claimed by update_book at the new title + author PDA when the claim moves; an existing index is rejected
+    #[account(mut)]
+    pub new_title_index: Option<UncheckedAccount<'info>>,
+    /// CHECK: Previous primary author's index PDA, verified by update_book; required when the primary author changes
+    #[account(mut)]
+    pub author_index: Option<UncheckedAccount<'info>>,
+    /// CHECK: New primary author's index PDA, verified by update_book and created on first use
+    #[account(mut)]
+    pub new_author_index: Option<UncheckedAccount<'info>>,
+    /// CHECK: Previous genre's index PDA, verified by update_book; required when the genre changes
+    #[account(mut)]
+    pub genre_index: Option<UncheckedAccount<'info>>,
+    /// CHECK: New genre's index PDA, verified by update_book and created on first use
+    #[account(mut)]
+    pub new_genre_index: Option<UncheckedAccount<'info>>,
+}
+
+/// Reassign a book's attribution (admin only)
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct ReassignBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init_if_needed, // Books added before the author index existed have no index yet
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", book.added_by.as_ref()],
+        bump
+    )]
+    pub previous_author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", new_owner.as_ref()],
+        bump
+    )]
+    pub new_author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", book.added_by.as_ref()],
+        bump
+    )]
+    pub previous_stats: Account<'info, CuratorStats>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", new_owner.as_ref()],
+        bump
+    )]
+    pub new_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Archive a book in place (admin only)
+#[derive(Accounts)]
+pub struct ArchiveBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init_if_needed, // Books added before the catalog index existed have no index yet
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Put a book under review or clear it (moderators and higher roles)
+#[derive(Accounts)]
+pub struct FlagBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    pub authority: Signer<'info>,
+}
+
+/// Remove a book from catalog (admin, or curator/moderator when permitted)
+#[derive(Accounts)]
+pub struct RemoveBook<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump,
+        close = authority
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init_if_needed, // Books added before the author index existed have no index yet
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", book.added_by.as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed, // Books added before the catalog index existed have no index yet
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    /// Releases the book's title claim (keyed by `book.title_key`); required unless the book
+    /// was added before the title index existed
+    #[account(
+        mut,
+        close = authority,
+        constraint = title_index.book_id == book.id @ DoLError::TitleIndexMismatch
+    )]
+    pub title_index: Option<Account<'info, BookTitleIndex>>,
+    /// CHECK: Primary author's index PDA, verified by remove_book; skipped if never created
+    #[account(mut)]
+    pub author_index: UncheckedAccount<'info>,
+    /// CHECK: Genre's index PDA, verified by remove_book; skipped if never created
+    #[account(mut)]
+    pub genre_index: UncheckedAccount<'info>,
+    /// CHECK: Sequence slot PDA for `book.seq`, verified and closed by remove_book, leaving a gap
+    /// that pagination skips; left alone unless it holds the book
+    #[account(mut)]
+    pub seq_index: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed, // Books added before stats existed have no record yet
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", book.added_by.as_ref()],
+        bump
+    )]
+    pub curator_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a batch of books, all added by `added_by`, passed as remaining accounts
+#[derive(Accounts)]
+#[instruction(added_by: Pubkey)]
+pub struct RemoveBooksBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        init_if_needed, // Books added before the catalog index existed have no index yet
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    #[account(
+        init_if_needed, // Books added before the author index existed have no index yet
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", added_by.as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed, // Books added before stats existed have no record yet
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", added_by.as_ref()],
+        bump
+    )]
+    pub curator_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Close a curator's books passed as remaining accounts (admin only)
+#[derive(Accounts)]
+#[instruction(target_curator: Pubkey)]
+pub struct PurgeCuratorBooks<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        init_if_needed, // Books added before the catalog index existed have no index yet
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    #[account(
+        init_if_needed, // Books added before the author index existed have no index yet
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", target_curator.as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed, // Books added before stats existed have no record yet
+        payer = authority,
+        space = CURATOR_STATS_SPACE,
+        seeds = [b"curator_stats", target_curator.as_ref()],
+        bump
+    )]
+    pub curator_stats: Account<'info, CuratorStats>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Point a book at a newer edition (admin only)
+#[derive(Accounts)]
+#[instruction(old_id: [u8; 16], new_id: [u8; 16])]
+pub struct SupersedeBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", old_id.as_ref()],
+        bump = old_book.bump,
+        constraint = old_id != new_id @ DoLError::SelfSupersedeNotAllowed
+    )]
+    pub old_book: Account<'info, Book>,
+    #[account(
+        seeds = [b"book", new_id.as_ref()], // New edition must exist
+        bump = new_book.bump
+    )]
+    pub new_book: Account<'info, Book>,
+    pub authority: Signer<'info>,
+}
+
+/// Move a book to a new ID (super admin only)
+#[derive(Accounts)]
+#[instruction(new_id: [u8; 16])]
+pub struct MigrateBookId<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", old_book.id.as_ref()],
+        bump = old_book.bump,
+        close = authority
+    )]
+    pub old_book: Account<'info, Book>,
+    #[account(
+        init,
+        payer = authority,
+        space = old_book.to_account_info().data_len(), // Same size, so every field fits
+        seeds = [b"book", new_id.as_ref()],
+        bump
+    )]
+    pub new_book: Account<'info, Book>,
+    #[account(
+        init_if_needed, // Books added before the author index existed have no index yet
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_AUTHOR_BOOKS * 16) + 1,
+        seeds = [b"author_books", old_book.added_by.as_ref()],
+        bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+    #[account(
+        init_if_needed, // Books added before the catalog index existed have no index yet
+        payer = authority,
+        space = BOOK_INDEX_SPACE,
+        seeds = [b"book_index"],
+        bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+    /// Title claim the book holds (keyed by `old_book.title_key`); required unless the book
+    /// was added before the title index existed
+    #[account(
+        mut,
+        seeds = [b"title_idx", old_book.title_key.as_ref()],
+        bump = title_index.bump,
+        constraint = title_index.book_id == old_book.id @ DoLError::TitleIndexMismatch
+    )]
+    pub title_index: Option<Account<'info, BookTitleIndex>>,
+    /// CHECK: Primary author's index PDA, verified by migrate_book_id; skipped if never created
+    #[account(mut)]
+    pub author_index: UncheckedAccount<'info>,
+    /// CHECK: Genre's index PDA, verified by migrate_book_id; skipped if never created
+    #[account(mut)]
+    pub genre_index: UncheckedAccount<'info>,
+    /// CHECK: Sequence slot PDA for `old_book.seq`, verified by migrate_book_id; left alone
+    /// unless it holds the book
+    #[account(mut)]
+    pub seq_index: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Manage admin roles (super admin or admin can manage roles)
+#[derive(Accounts)]
+pub struct ManageAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// List books added by an authority (public access)
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct ListAuthorBooks<'info> {
+    #[account(
+        seeds = [b"author_books", authority.as_ref()],
+        bump = author_books.bump
+    )]
+    pub author_books: Account<'info, AuthorBooks>,
+}
+
+/// List active books from the catalog-wide index (public access)
+#[derive(Accounts)]
+pub struct ListBooks<'info> {
+    #[account(
+        seeds = [b"book_index"],
+        bump = book_index.bump
+    )]
+    pub book_index: Account<'info, BookIndex>,
+}
+
+/// Read global program state (public access)
+#[derive(Accounts)]
+pub struct GetState<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+}
+
+/// Read book information (public access)
+#[derive(Accounts)]
+pub struct GetBook<'info> {
+    pub book: Account<'info, Book>,
+}
+
+/// Record a read of a book by a library card holder
+#[derive(Accounts)]
+pub struct RecordRead<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"library_card", reader.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init_if_needed,
+        payer = reader,
+        space = ANCHOR_DISCRIMINATOR + 32 + 16 + 8 + 8 + 1,
+        seeds = [b"read", reader.key().as_ref(), book.id.as_ref()], // One receipt per reader per book
+        bump
+    )]
+    pub read_receipt: Account<'info, ReadReceipt>,
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Save a library card holder's reading progress in a book
+#[derive(Accounts)]
+pub struct UpdateProgress<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        mut,
+        seeds = [b"library_card", reader.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init_if_needed,
+        payer = reader,
+        space = ANCHOR_DISCRIMINATOR + 32 + 16 + 1 + 8 + 1,
+        seeds = [b"progress", reader.key().as_ref(), book.id.as_ref()], // One record per reader per book
+        bump
+    )]
+    pub progress: Account<'info, ReadingProgress>,
+    #[account(mut)]
+    pub reader: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Attest a book review (curator only)
+#[derive(Accounts)]
+#[instruction(book_id: [u8; 16])]
+pub struct AttestBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book_id.as_ref()], // Book must exist
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        init,
+        payer = curator,
+        space = ANCHOR_DISCRIMINATOR + 16 + 32 + 8 + 1,
+        seeds = [b"attest", book_id.as_ref(), curator.key().as_ref()], // One attestation per curator per book
+        bump
+    )]
+    pub attestation: Account<'info, ReviewAttestation>,
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Revoke a book review attestation (attesting curator only)
+#[derive(Accounts)]
+#[instruction(book_id: [u8; 16])]
+pub struct RevokeAttestation<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book_id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        mut,
+        close = curator,
+        seeds = [b"attest", book_id.as_ref(), curator.key().as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, ReviewAttestation>,
+    #[account(mut)]
+    pub curator: Signer<'info>,
+}
+
+/// Rate a book as a library card holder
+#[derive(Accounts)]
+pub struct RateBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        mut,
+        seeds = [b"library_card", rater.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init_if_needed,
+        payer = rater,
+        space = ANCHOR_DISCRIMINATOR + 32 + 16 + 1 + 8 + 1,
+        seeds = [b"rating", book.key().as_ref(), rater.key().as_ref()], // One rating per rater per book
+        bump
+    )]
+    pub rating_receipt: Account<'info, RatingReceipt>,
+    #[account(mut)]
+    pub rater: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
 
-        // Unpause the program
-        dol_state.set_paused(false);
-        msg!("Program unpaused by super admin: {:?}", signer);
-        Ok(())
-    }
+/// Export a book by ID with deployment context (public access)
+#[derive(Accounts)]
+#[instruction(id: [u8; 16])]
+pub struct ExportBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"book", id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
 }
 
-// Account structures
-/// Global program state - tracks admin authorities and book catalog size
-#[account]
-pub struct DoLState {
-    pub super_admin: Pubkey,     // Current super admin with full control
-    pub admins: Vec<Pubkey>,     // Library admins (can add/remove books, manage roles)
-    pub moderators: Vec<Pubkey>, // Moderators (can flag content, limited admin powers)
-    pub curators: Vec<Pubkey>,   // Curators (can add books but not remove)
-    pub book_count: u64,         // Total books added (for analytics and metrics)
-    pub version: u8,             // Program version for future upgrades
-    pub flags: u8,               // Bit flags: bit 0 = is_paused
-    pub bump: u8,                // PDA bump seed
-    // Super admin transfer security fields
-    pub pending_super_admin: Option<Pubkey>, // Pending new super admin (if transfer initiated)
-    pub transfer_initiated_at: i64,          // Timestamp when transfer was initiated
-    pub transfer_timelock: i64, // Required delay before transfer can be confirmed (default: 7 days)
-    // Emergency recovery fields
-    pub emergency_recovery_threshold: u8, // Number of admin signatures required for emergency recovery
-    pub emergency_recovery_initiated_at: i64, // Timestamp when emergency recovery was initiated
-    pub emergency_recovery_votes: Vec<Pubkey>, // Admins who have voted for emergency recovery
-    pub emergency_recovery_new_admin: Option<Pubkey>, // Proposed new super admin for recovery
-    // Rate limiting fields
-    pub last_book_addition: i64,     // Timestamp of last book addition
-    pub books_added_today: u16,      // Number of books added in current day
-    pub last_book_addition_day: i64, // Day (unix timestamp / 86400) of last book count reset
+/// Resolve a user's role from state and their optional library card (public access)
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct GetUserRole<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"library_card", user.as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Option<Account<'info, LibraryCard>>,
 }
 
-/// Individual book record with metadata and IPFS content reference
-#[account]
-pub struct Book {
-    pub id: [u8; 16],          // Unique book ID (UUID generated by client)
-    pub title: String,         // Book title
-    pub author: String,        // Author name
-    pub ipfs_hash: String,     // IPFS hash pointing to book content
-    pub genre: String,         // Book genre/category
-    pub publication_year: u16, // Publication year (optional, 0 if unknown)
-    pub added_timestamp: i64,  // When book was added to catalog
-    pub added_by: Pubkey,      // Who added this book (for audit trail)
-    pub bump: u8,              // PDA bump seed
-    pub reserved: [u8; 32],    // Reserved space for future features
+/// Read a user's profile from their optional PDAs (public access)
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct GetUserProfile<'info> {
+    #[account(
+        seeds = [b"library_card", user.as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Option<Account<'info, LibraryCard>>,
+    #[account(
+        seeds = [b"curator_stats", user.as_ref()],
+        bump = curator_stats.bump
+    )]
+    pub curator_stats: Option<Account<'info, CuratorStats>>,
+    #[account(
+        seeds = [b"wishlist", user.as_ref()],
+        bump = wishlist.bump
+    )]
+    pub wishlist: Option<Account<'info, Wishlist>>,
 }
 
-/// Library Card NFT that grants reading access to all books
-#[account]
-pub struct LibraryCard {
-    pub owner: Pubkey,       // Card holder's wallet address
-    pub mint_timestamp: i64, // When card was minted
-    pub bump: u8,            // PDA bump seed
-    pub reserved: [u8; 48],  // Reserved space for future features (increased)
+/// Read a book's rating histogram by ID (public access)
+#[derive(Accounts)]
+#[instruction(book_id: [u8; 16])]
+pub struct GetRatingDistribution<'info> {
+    #[account(
+        seeds = [b"book", book_id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
 }
 
-// Context structures
-/// Initialize the DoL program state account (super admin only)
+/// Post or edit a review (library card holders only)
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+pub struct PostReview<'info> {
     #[account(
-        init,
-        payer = super_admin,
-        space = ANCHOR_DISCRIMINATOR + 32 + (4 + MAX_ADMINS * 32) + (4 + MAX_MODERATORS * 32) + (4 + MAX_CURATORS * 32) + 8 + 1 + 1 + 1 + (1 + 32) + 8 + 8 + 1 + 8 + (4 + MAX_ADMINS * 32) + (1 + 32) + 8 + 2 + 8,
-        seeds = [b"dol_state"],              // Global singleton PDA
-        bump
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
     )]
     pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        mut,
+        seeds = [b"library_card", reviewer.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        space = ANCHOR_DISCRIMINATOR + 32 + 16 + (4 + MAX_REVIEW_TEXT_BYTES) + 1 + 8 + 1, // Sized for the longest text so edits fit
+        seeds = [b"review", book.id.as_ref(), reviewer.key().as_ref()], // One review per reviewer per book
+        bump
+    )]
+    pub review: Account<'info, Review>,
     #[account(mut)]
-    pub super_admin: Signer<'info>,
+    pub reviewer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Mint a library card NFT for a user (one per wallet)
+/// Delete a review (author or moderator)
 #[derive(Accounts)]
-pub struct MintLibraryCard<'info> {
+#[instruction(book_id: [u8; 16])]
+pub struct DeleteReview<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book_id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        mut,
+        close = reviewer,
+        seeds = [b"review", book_id.as_ref(), review.reviewer.as_ref()],
+        bump = review.bump
+    )]
+    pub review: Account<'info, Review>,
+    /// CHECK: Receives the rent refund; constrained to the review's author
+    #[account(mut, address = review.reviewer)]
+    pub reviewer: UncheckedAccount<'info>,
+    /// CHECK: The author's library card PDA, verified by delete_review; skipped if the card
+    /// has moved wallets
+    #[account(mut)]
+    pub library_card: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+/// Report a book (library card holders only)
+#[derive(Accounts)]
+#[instruction(reason_code: u8, details: String)]
+pub struct ReportBook<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"library_card", reporter.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
     #[account(
         init,
-        payer = user,
-        space = ANCHOR_DISCRIMINATOR + 32 + 8 + 1 + 48,  // Removed card_id, increased reserved
-        seeds = [b"library_card", user.key().as_ref()],    // User-specific PDA
+        payer = reporter,
+        space = ANCHOR_DISCRIMINATOR + 32 + 32 + 1 + (4 + details.len()) + 8 + 1,
+        seeds = [b"report", book.key().as_ref(), reporter.key().as_ref()], // One open report per reporter per book
         bump
     )]
-    pub library_card: Account<'info, LibraryCard>,
+    pub report: Account<'info, BookReport>,
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub reporter: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Add a new book to the catalog (super admin, admin, or curator)
+/// Resolve a book report (moderators and higher roles)
 #[derive(Accounts)]
-#[instruction(id: [u8; 16], title: String, author: String, ipfs_hash: String, genre: String)]
-pub struct AddBook<'info> {
+pub struct ResolveReport<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        mut,
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
+    pub book: Account<'info, Book>,
     #[account(
         mut,
+        close = reporter,
+        seeds = [b"report", book.key().as_ref(), report.reporter.as_ref()],
+        bump = report.bump
+    )]
+    pub report: Account<'info, BookReport>,
+    /// CHECK: Receives the rent refund; constrained to the report's author
+    #[account(mut, address = report.reporter)]
+    pub reporter: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+/// Create a book collection (library card holders only)
+#[derive(Accounts)]
+#[instruction(name_hash: [u8; 32])]
+pub struct CreateCollection<'info> {
+    #[account(
         seeds = [b"dol_state"],
         bump = dol_state.bump
     )]
     pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"library_card", creator.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
     #[account(
         init,
-        payer = authority,
-        space = ANCHOR_DISCRIMINATOR + 16 + (4 + title.len()) + (4 + author.len()) + (4 + ipfs_hash.len()) + (4 + genre.len()) + 2 + 8 + 32 + 1 + 32,
-        seeds = [b"book", id.as_ref()],     // UUID-based PDA addressing
+        payer = creator,
+        space = COLLECTION_SPACE, // Sized for the longest name, description and book list so edits fit
+        seeds = [b"collection", creator.key().as_ref(), name_hash.as_ref()],
         bump
     )]
-    pub book: Account<'info, Book>,
+    pub collection: Account<'info, Collection>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Update book metadata (super admin, admin, or curator)
+/// Add a book to a collection (the collection's creator, with a library card)
 #[derive(Accounts)]
-pub struct UpdateBook<'info> {
+pub struct AddBookToCollection<'info> {
     #[account(
         seeds = [b"dol_state"],
         bump = dol_state.bump
     )]
     pub dol_state: Account<'info, DoLState>,
-    #[account(mut)]
+    #[account(
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
+    )]
     pub book: Account<'info, Book>,
-    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"library_card", creator.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        mut,
+        seeds = [b"collection", creator.key().as_ref(), collection.name_hash.as_ref()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+    pub creator: Signer<'info>,
 }
 
-/// Remove a book from catalog (admin only)
+/// Remove a book from a collection (the collection's creator, with a library card)
 #[derive(Accounts)]
-pub struct RemoveBook<'info> {
+pub struct RemoveBookFromCollection<'info> {
     #[account(
-        mut,
         seeds = [b"dol_state"],
         bump = dol_state.bump
     )]
     pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"library_card", creator.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
     #[account(
         mut,
-        close = authority
+        seeds = [b"collection", creator.key().as_ref(), collection.name_hash.as_ref()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, Collection>,
+    pub creator: Signer<'info>,
+}
+
+/// Create an annotation on a book (library card holders only)
+#[derive(Accounts)]
+#[instruction(index: u16)]
+pub struct CreateAnnotation<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"book", book.id.as_ref()],
+        bump = book.bump
     )]
     pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"library_card", reader.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init,
+        payer = reader,
+        space = ANNOTATION_SPACE, // Sized for the longest note so edits fit
+        seeds = [b"annotation", reader.key().as_ref(), book.id.as_ref(), index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub annotation: Account<'info, Annotation>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub reader: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// Manage admin roles (super admin or admin can manage roles)
+/// Update an annotation (its reader, with a library card)
 #[derive(Accounts)]
-pub struct ManageAdmin<'info> {
+#[instruction(book_id: [u8; 16], index: u16)]
+pub struct UpdateAnnotation<'info> {
     #[account(
-        mut,
         seeds = [b"dol_state"],
         bump = dol_state.bump
     )]
     pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"library_card", reader.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        mut,
+        seeds = [b"annotation", reader.key().as_ref(), book_id.as_ref(), index.to_le_bytes().as_ref()],
+        bump = annotation.bump
+    )]
+    pub annotation: Account<'info, Annotation>,
+    pub reader: Signer<'info>,
+}
+
+/// Delete an annotation (its reader, with a library card)
+#[derive(Accounts)]
+#[instruction(book_id: [u8; 16], index: u16)]
+pub struct DeleteAnnotation<'info> {
+    #[account(
+        seeds = [b"library_card", reader.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        mut,
+        close = reader,
+        seeds = [b"annotation", reader.key().as_ref(), book_id.as_ref(), index.to_le_bytes().as_ref()],
+        bump = annotation.bump
+    )]
+    pub annotation: Account<'info, Annotation>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub reader: Signer<'info>,
 }
 
-/// Read book information (public access)
+/// Add a book to a member's wishlist (library card holders only)
 #[derive(Accounts)]
-pub struct GetBook<'info> {
+#[instruction(book_id: [u8; 16])]
+pub struct AddToWishlist<'info> {
+    #[account(
+        seeds = [b"dol_state"],
+        bump = dol_state.bump
+    )]
+    pub dol_state: Account<'info, DoLState>,
+    #[account(
+        seeds = [b"book", book_id.as_ref()], // Book must exist
+        bump = book.bump
+    )]
     pub book: Account<'info, Book>,
+    #[account(
+        seeds = [b"library_card", user.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = WISHLIST_SPACE,
+        seeds = [b"wishlist", user.key().as_ref()],
+        bump
+    )]
+    pub wishlist: Account<'info, Wishlist>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a book from a member's wishlist (library card holders only)
+#[derive(Accounts)]
+pub struct RemoveFromWishlist<'info> {
+    #[account(
+        seeds = [b"library_card", user.key().as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Account<'info, LibraryCard>,
+    #[account(
+        mut,
+        seeds = [b"wishlist", user.key().as_ref()],
+        bump = wishlist.bump
+    )]
+    pub wishlist: Account<'info, Wishlist>,
+    pub user: Signer<'info>,
+}
+
+/// Read a reader's receipt for a book (public access)
+#[derive(Accounts)]
+pub struct GetReadReceipt<'info> {
+    #[account(
+        seeds = [b"read", read_receipt.reader.as_ref(), read_receipt.book_id.as_ref()],
+        bump = read_receipt.bump
+    )]
+    pub read_receipt: Account<'info, ReadReceipt>,
 }
 
-/// Verify library card ownership for client access control
+/// Verify library card ownership for client access control (public access, CPI-friendly)
 #[derive(Accounts)]
+#[instruction(user: Pubkey)]
 pub struct VerifyAccess<'info> {
+    #[account(
+        seeds = [b"library_card", user.as_ref()],
+        bump = library_card.bump
+    )]
+    pub library_card: Option<Account<'info, LibraryCard>>,
+}
+
+/// Verify the signer's own library card
+#[derive(Accounts)]
+pub struct VerifyOwnCard<'info> {
+    #[account(
+        seeds = [b"library_card", owner.key().as_ref()],
+        bump = library_card.bump
+    )]
     pub library_card: Account<'info, LibraryCard>,
+    pub owner: Signer<'info>,
 }
 
 // Custom error types
@@ -1062,19 +7612,19 @@ pub struct VerifyAccess<'info> {
 pub enum DoLError {
     #[msg("Unauthorized: Only admin can perform this action")]
     Unauthorized,
-    #[msg("Book title invalid (1-100 characters required)")]
+    #[msg("Book title invalid (1-100 characters, at most 200 bytes)")]
     TitleTooLong,
-    #[msg("Author name invalid (1-50 characters required)")]
+    #[msg("Author name invalid (1-50 characters, at most 100 bytes)")]
     AuthorTooLong,
     #[msg("IPFS hash invalid format (must be valid IPFS hash starting with Qm or baf)")]
     InvalidIpfsHash,
-    #[msg("Genre invalid (1-30 characters required)")]
+    #[msg("Genre invalid (1-30 characters, at most 60 bytes)")]
     GenreTooLong,
     #[msg("Library card already exists for this user")]
     CardAlreadyExists,
     #[msg("Book ID must be a valid UUID v4")]
     InvalidBookId,
-    #[msg("Book with this ID already exists")]
+    #[msg("Book already exists (same ID, or same title and author)")]
     BookAlreadyExists,
     // Role-based access control errors
     #[msg("Access denied: Only super admin can perform this action")]
@@ -1130,4 +7680,232 @@ pub enum DoLError {
     RateLimitExceeded,
     #[msg("Daily limit exceeded: maximum books per day reached")]
     DailyLimitExceeded,
+    // Counter errors
+    #[msg("Counter overflow or underflow")]
+    CountOverflow,
+    // Rating errors
+    #[msg("Rating invalid (1-5 stars required)")]
+    InvalidRating,
+    #[msg("Book already rated by this card holder")]
+    AlreadyRated,
+    // Pause errors
+    #[msg("Pause toggle cooldown active: wait before toggling pause again")]
+    PauseToggleCooldown,
+    #[msg("Cooldown invalid (0-300 seconds required)")]
+    InvalidCooldown,
+    // Edition errors
+    #[msg("A book cannot supersede itself")]
+    SelfSupersedeNotAllowed,
+    // String validation errors
+    #[msg("Input length invalid for this field")]
+    InvalidLength,
+    #[msg("Invalid input: contains control characters")]
+    InvalidCharacters,
+    // Content rating errors
+    #[msg("Content rating must be 0 (unrated) through 3 (mature)")]
+    InvalidContentRating,
+    // Sponsored minting errors
+    #[msg("Too many users in one sponsored batch")]
+    SponsoredBatchTooLarge,
+    #[msg("Remaining accounts must be the users' library card PDAs, in order")]
+    CardAccountMismatch,
+    // Moderation errors
+    #[msg("Book is not yet visible to readers")]
+    BookNotYetVisible,
+    #[msg("Visibility delay must be between 0 and 7 days")]
+    InvalidVisibilityDelay,
+    // Pause liveness errors
+    #[msg("Program is not paused")]
+    ProgramNotPaused,
+    #[msg("Force unpause requires approval from a majority of admins")]
+    InsufficientUnpauseApprovals,
+    #[msg("Max pause duration must be between 0 and 365 days")]
+    InvalidPauseDuration,
+    // Book length errors
+    #[msg("Page count exceeds the maximum")]
+    InvalidPageCount,
+    #[msg("Word count exceeds the maximum")]
+    InvalidWordCount,
+    // External identifier errors
+    #[msg("LCCN must be printable characters padded with trailing zeros")]
+    InvalidLccn,
+    // Timelock errors
+    #[msg("Transfer timelock must be between 1 hour and 30 days")]
+    InvalidTimelock,
+    // Publication year errors
+    #[msg("Publication year must be between 1 and the current year")]
+    InvalidPublicationYear,
+    // Archive errors
+    #[msg("Book is already archived")]
+    BookAlreadyArchived,
+    // Report errors
+    #[msg("Invalid report reason code")]
+    InvalidReportReason,
+    #[msg("Invalid report resolution action")]
+    InvalidReportAction,
+    // Genre errors
+    #[msg("Genre label is only allowed for the Other genre")]
+    GenreLabelNotAllowed,
+    // Library card errors
+    #[msg("Signer does not own this library card")]
+    NotCardOwner,
+    // Recovery threshold errors
+    #[msg("Recovery threshold must be between 1 and MAX_ADMINS")]
+    InvalidRecoveryThreshold,
+    // Super admin claim errors
+    #[msg("Only the pending super admin can confirm the transfer")]
+    NotPendingSuperAdmin,
+    // Book attribution errors
+    #[msg("New book owner must be a different curator, admin, or super admin")]
+    InvalidBookOwner,
+    // Category pause errors
+    #[msg("Book additions and updates are paused")]
+    BooksPaused,
+    #[msg("Library card minting is paused")]
+    CardsPaused,
+    #[msg("Pause flags may only contain the books and cards bits")]
+    InvalidPauseFlags,
+    // Title index errors
+    #[msg("Title index entry belongs to a different book")]
+    TitleIndexMismatch,
+    // Language errors
+    #[msg("Language must be a two-letter lowercase ISO 639-1 code")]
+    InvalidLanguage,
+    // Version errors
+    #[msg("New version must be greater than the current version")]
+    InvalidVersion,
+    // Batch removal errors
+    #[msg("Too many books in one batch removal")]
+    RemoveBatchTooLarge,
+    #[msg("Batch accounts must be book PDAs, each followed by its index accounts")]
+    InvalidBatchAccounts,
+    // Reading progress errors
+    #[msg("Reading progress must be between 0 and 100 percent")]
+    InvalidProgress,
+    // Treasury errors
+    #[msg("Card fee must be 0 or at least the treasury's rent-exempt minimum")]
+    InvalidCardFee,
+    #[msg("Withdrawal must be nonzero and leave the treasury empty or rent-exempt")]
+    InvalidWithdrawAmount,
+    // ISBN errors
+    #[msg("ISBN must be 13 ASCII digits with a valid ISBN-13 check digit")]
+    InvalidIsbn,
+    // Moderation review errors
+    #[msg("Book is under moderator review")]
+    BookUnderReview,
+    // Account space errors
+    #[msg("Book account is too small for the update; provide a payer to fund the reallocation")]
+    AccountTooSmall,
+    // Catalog index errors
+    #[msg("Catalog is full")]
+    CatalogFull,
+    // Emergency recovery timelock errors
+    #[msg("Emergency recovery timelock has not expired yet")]
+    RecoveryTimelockNotExpired,
+    #[msg("Emergency recovery does not have enough votes to execute")]
+    InsufficientRecoveryVotes,
+    #[msg("Recovery timelock must be between 0 and MAX_RECOVERY_TIMELOCK")]
+    InvalidRecoveryTimelock,
+
+    // Author errors
+    #[msg("A book needs between 1 and 3 authors")]
+    TooManyAuthors,
+
+    // Collection errors
+    #[msg("Collection name hash does not match the name")]
+    CollectionNameMismatch,
+    #[msg("Collection is full")]
+    CollectionFull,
+    #[msg("Book is already in this collection")]
+    BookAlreadyInCollection,
+    #[msg("Book is not in this collection")]
+    BookNotInCollection,
+
+    // Annotation errors
+    #[msg("Annotation index is out of range")]
+    InvalidAnnotationIndex,
+    #[msg("Annotation range must end after it starts")]
+    InvalidAnnotationRange,
+
+    // Initialization errors
+    #[msg("Program state is already initialized")]
+    AlreadyInitialized,
+
+    // Wishlist errors
+    #[msg("Wishlist is full")]
+    WishlistFull,
+    #[msg("Book is already on the wishlist")]
+    BookAlreadyInWishlist,
+    #[msg("Book is not on the wishlist")]
+    BookNotInWishlist,
+
+    // Role address errors
+    #[msg("Role address cannot be the default public key")]
+    InvalidRoleAddress,
+    #[msg("Address already holds a conflicting role")]
+    RoleConflict,
+
+    // Card tier errors
+    #[msg("Card tier is invalid or not above the card's current tier")]
+    InvalidCardTier,
+    #[msg("Library card tier is too low for this content")]
+    InsufficientTier,
+
+    // Permission errors
+    #[msg("Permissions contain undefined bits")]
+    InvalidPermissions,
+
+    // Permission category errors
+    #[msg("Signer is not allowed to add books")]
+    CannotAddBooks,
+    #[msg("Signer is not allowed to manage roles")]
+    CannotManageRoles,
+
+    // Author and genre index errors
+    #[msg("Author or genre index is full")]
+    IndexFull,
+    #[msg("Account is not the index for this book")]
+    IndexMismatch,
+
+    // Description errors
+    #[msg("Book description invalid (up to 500 characters, at most 1000 bytes)")]
+    DescriptionTooLong,
+
+    // Card age errors
+    #[msg("Library card is too new for this action")]
+    CardTooNew,
+    #[msg("Minimum card age must be between 0 and 30 days")]
+    InvalidCardAge,
+
+    // Role resignation errors
+    #[msg("Signer holds no admin, curator, or moderator role")]
+    NotInAnyRole,
+    #[msg("Super admin cannot resign; transfer or renounce the role instead")]
+    SuperAdminCannotResign,
+
+    // Recovery quorum errors
+    #[msg("Removing this admin would leave fewer admins than the recovery threshold")]
+    WouldBreakRecoveryQuorum,
+
+    // Archived read errors
+    #[msg("Book is archived")]
+    BookArchived,
+
+    // Cover policy errors
+    #[msg("Cover IPFS hash must differ from the content IPFS hash")]
+    CoverMatchesContent,
+
+    // Book export errors
+    #[msg("Exported book record exceeds the 1024-byte return data limit")]
+    ExportTooLarge,
+
+    // Index account errors
+    #[msg("The book's index accounts must be provided")]
+    IndexRequired,
+    #[msg("A payer is required to create the book's new index")]
+    PayerRequired,
+
+    // Batch ownership errors
+    #[msg("Every book in a batch removal must have been added by the given authority")]
+    BatchAddedByMismatch,
 }
@@ -13,8 +13,19 @@ import {
   loadKeypair,
   getDoLStatePDA,
   getLibraryCardPDA,
+  getTreasuryPDA,
   getBookPDA,
+  getAuthorBooksPDA,
+  getCuratorStatsPDA,
+  getBookIndexPDA,
+  getTitleIndexPDA,
+  getAuthorIndexPDA,
+  getGenreIndexPDA,
+  getSeqIndexPDA,
   generateBookId,
+  parseGenre,
+  encodeString,
+  readNextBookSeq,
   getInstructionDiscriminator,
   validateIpfsHash,
   showUsage,
@@ -64,8 +75,11 @@ async function mintLibraryCard(
   const libraryCardPDA = getLibraryCardPDA(user.publicKey);
   const discriminator = getInstructionDiscriminator("mint_library_card");
 
+  // The treasury receives the card fee, when one is set
   const instruction = new TransactionInstruction({
     keys: [
+      { pubkey: getDoLStatePDA(), isSigner: false, isWritable: true },
+      { pubkey: getTreasuryPDA(), isSigner: false, isWritable: true },
       { pubkey: libraryCardPDA, isSigner: false, isWritable: true },
       { pubkey: user.publicKey, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
@@ -96,7 +110,7 @@ async function addBook(
   title: string,
   author: string,
   ipfsHash: string,
-  genre: string,
+  genreName: string,
 ): Promise<void> {
   console.log("📚 Adding book...");
 
@@ -105,33 +119,68 @@ async function addBook(
     return;
   }
 
-  const bookId = generateBookId();
   const dolStatePDA = getDoLStatePDA();
+  const dolState = await connection.getAccountInfo(dolStatePDA);
+  if (!dolState) {
+    console.error("❌ DoL program not initialized");
+    return;
+  }
+
+  const bookId = generateBookId();
   const bookPDA = getBookPDA(bookId);
+  const { genre, label } = parseGenre(genreName);
   const discriminator = getInstructionDiscriminator("add_book");
 
-  const titleBytes = Buffer.from(title, "utf8");
-  const authorBytes = Buffer.from(author, "utf8");
-  const ipfsBytes = Buffer.from(ipfsHash, "utf8");
-  const genreBytes = Buffer.from(genre, "utf8");
-
+  // Only the core fields are set; the rest take add_book's "none" values
   const instructionData = Buffer.concat([
     discriminator,
     bookId,
-    Buffer.from([titleBytes.length, 0, 0, 0]),
-    titleBytes,
-    Buffer.from([authorBytes.length, 0, 0, 0]),
-    authorBytes,
-    Buffer.from([ipfsBytes.length, 0, 0, 0]),
-    ipfsBytes,
-    Buffer.from([genreBytes.length, 0, 0, 0]),
-    genreBytes,
+    encodeString(title),
+    Buffer.from([1, 0, 0, 0]), // Single-author vec
+    encodeString(author),
+    encodeString(ipfsHash),
+    Buffer.from([genre]),
+    encodeString(label), // genre_label (Other only)
+    encodeString(""), // metadata_ipfs_hash
+    Buffer.from([0]), // content_rating (unrated)
+    Buffer.alloc(4), // page_count (unknown)
+    Buffer.alloc(4), // word_count (unknown)
+    Buffer.alloc(8), // oclc (none)
+    Buffer.alloc(12), // lccn (none)
+    Buffer.from([0]), // publication_year: None
+    Buffer.from([0]), // cover_ipfs_hash: None
+    Buffer.from([0]), // language: None (program default)
+    Buffer.from([0]), // isbn: None
+    Buffer.from([0]), // description: None
   ]);
 
   const instruction = new TransactionInstruction({
     keys: [
       { pubkey: dolStatePDA, isSigner: false, isWritable: true },
       { pubkey: bookPDA, isSigner: false, isWritable: true },
+      {
+        pubkey: getAuthorBooksPDA(admin.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: getBookIndexPDA(), isSigner: false, isWritable: true },
+      {
+        pubkey: getTitleIndexPDA(title, author),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: getAuthorIndexPDA(author), isSigner: false, isWritable: true },
+      { pubkey: getGenreIndexPDA(genre), isSigner: false, isWritable: true },
+      {
+        pubkey: getSeqIndexPDA(readNextBookSeq(dolState.data)),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: getCuratorStatsPDA(admin.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
       { pubkey: admin.publicKey, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
@@ -204,11 +253,12 @@ async function updateBook(
     instructionData = Buffer.concat([instructionData, Buffer.from([0])]); // None
   }
 
-  // Serialize Option<String> for author
+  // Serialize Option<Vec<String>> for authors (single author)
   if (authorBytes) {
     instructionData = Buffer.concat([
       instructionData,
       Buffer.from([1]), // Some
+      Buffer.from([1, 0, 0, 0]), // Vec length
       Buffer.from([authorBytes.length, 0, 0, 0]), // Length
       authorBytes,
     ]);
@@ -481,6 +531,7 @@ async function addAdmin(
   connection: Connection,
   authority: Keypair,
   newAdminPubkey: PublicKey,
+  note: string = "", // Governance note recorded with the change
 ): Promise<void> {
   console.log("👨‍💼 Adding new admin...");
 
@@ -490,6 +541,7 @@ async function addAdmin(
   const instructionData = Buffer.concat([
     discriminator,
     newAdminPubkey.toBuffer(),
+    encodeString(note),
   ]);
 
   const instruction = new TransactionInstruction({
@@ -520,6 +572,7 @@ async function removeAdmin(
   connection: Connection,
   authority: Keypair,
   adminToRemove: PublicKey,
+  note: string = "", // Governance note recorded with the change
 ): Promise<void> {
   console.log("🗑️ Removing admin...");
 
@@ -529,6 +582,7 @@ async function removeAdmin(
   const instructionData = Buffer.concat([
     discriminator,
     adminToRemove.toBuffer(),
+    encodeString(note),
   ]);
 
   const instruction = new TransactionInstruction({
@@ -559,6 +613,7 @@ async function addCurator(
   connection: Connection,
   authority: Keypair,
   newCuratorPubkey: PublicKey,
+  note: string = "", // Governance note recorded with the change
 ): Promise<void> {
   console.log("📚 Adding new curator...");
 
@@ -568,6 +623,7 @@ async function addCurator(
   const instructionData = Buffer.concat([
     discriminator,
     newCuratorPubkey.toBuffer(),
+    encodeString(note),
   ]);
 
   const instruction = new TransactionInstruction({
@@ -598,6 +654,7 @@ async function removeCurator(
   connection: Connection,
   authority: Keypair,
   curatorToRemove: PublicKey,
+  note: string = "", // Governance note recorded with the change
 ): Promise<void> {
   console.log("🗑️ Removing curator...");
 
@@ -607,6 +664,7 @@ async function removeCurator(
   const instructionData = Buffer.concat([
     discriminator,
     curatorToRemove.toBuffer(),
+    encodeString(note),
   ]);
 
   const instruction = new TransactionInstruction({
@@ -751,6 +809,41 @@ async function cancelSuperAdminTransfer(
   }
 }
 
+async function renounceSuperAdmin(
+  connection: Connection,
+  authority: Keypair,
+): Promise<void> {
+  console.log("🔥 Renouncing super admin...");
+
+  const dolStatePDA = getDoLStatePDA();
+  const discriminator = getInstructionDiscriminator("renounce_super_admin");
+
+  const instruction = new TransactionInstruction({
+    keys: [
+      { pubkey: dolStatePDA, isSigner: false, isWritable: true },
+      { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+    ],
+    programId: PROGRAM_ID,
+    data: discriminator,
+  });
+
+  const transaction = new Transaction().add(instruction);
+
+  try {
+    const signature = await sendAndConfirmTransaction(connection, transaction, [
+      authority,
+    ]);
+
+    console.log("✅ renounce_super_admin accepted!");
+    console.log(
+      "⚠️  The first call only arms it; run the command again within 10 minutes to renounce for good",
+    );
+    console.log("🔗 Transaction signature:", signature);
+  } catch (error) {
+    console.error("Failed to renounce super admin:", error);
+  }
+}
+
 async function initiateEmergencyRecovery(
   connection: Connection,
   authority: Keypair,
@@ -823,7 +916,7 @@ async function voteEmergencyRecovery(
     console.log("✅ Emergency recovery vote submitted!");
     console.log("🗳️  Your vote has been recorded");
     console.log(
-      "💡 If enough votes are collected, recovery will execute automatically",
+      "💡 Once enough votes are in and the timelock passes, run 'execute-emergency-recovery'",
     );
     console.log("🔗 Transaction signature:", signature);
   } catch (error) {
@@ -831,6 +924,41 @@ async function voteEmergencyRecovery(
   }
 }
 
+async function executeEmergencyRecovery(
+  connection: Connection,
+  authority: Keypair,
+): Promise<void> {
+  console.log("🚨 Executing emergency recovery...");
+
+  const dolStatePDA = getDoLStatePDA();
+  const discriminator = getInstructionDiscriminator(
+    "execute_emergency_recovery",
+  );
+
+  const instruction = new TransactionInstruction({
+    keys: [
+      { pubkey: dolStatePDA, isSigner: false, isWritable: true },
+      { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+    ],
+    programId: PROGRAM_ID,
+    data: discriminator,
+  });
+
+  const transaction = new Transaction().add(instruction);
+
+  try {
+    const signature = await sendAndConfirmTransaction(connection, transaction, [
+      authority,
+    ]);
+
+    console.log("✅ Emergency recovery executed!");
+    console.log("👑 The proposed super admin is now in control");
+    console.log("🔗 Transaction signature:", signature);
+  } catch (error) {
+    console.error("Failed to execute emergency recovery:", error);
+  }
+}
+
 async function getStatus(connection: Connection): Promise<void> {
   console.log("📊 Checking DoL program status...");
 
@@ -1046,6 +1174,10 @@ async function main(): Promise<void> {
         await cancelSuperAdminTransfer(connection, payer);
         break;
 
+      case "renounce-super-admin":
+        await renounceSuperAdmin(connection, payer);
+        break;
+
       case "initiate-emergency-recovery":
         const emergencyAdminIndex = args.indexOf("--new-super-admin");
         if (emergencyAdminIndex === -1 || !args[emergencyAdminIndex + 1]) {
@@ -1071,6 +1203,10 @@ async function main(): Promise<void> {
         await voteEmergencyRecovery(connection, payer);
         break;
 
+      case "execute-emergency-recovery":
+        await executeEmergencyRecovery(connection, payer);
+        break;
+
       case "update-book":
         const updateBookIdIndex = args.indexOf("--book-id");
         if (updateBookIdIndex === -1 || !args[updateBookIdIndex + 1]) {
@@ -32,6 +32,134 @@ export function getBookPDA(bookId: Uint8Array): PublicKey {
   return bookPDA;
 }
 
+export function getTreasuryPDA(): PublicKey {
+  const [treasuryPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    PROGRAM_ID,
+  );
+  return treasuryPDA;
+}
+
+export function getAuthorBooksPDA(authority: PublicKey): PublicKey {
+  const [authorBooksPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("author_books"), authority.toBuffer()],
+    PROGRAM_ID,
+  );
+  return authorBooksPDA;
+}
+
+export function getCuratorStatsPDA(authority: PublicKey): PublicKey {
+  const [curatorStatsPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("curator_stats"), authority.toBuffer()],
+    PROGRAM_ID,
+  );
+  return curatorStatsPDA;
+}
+
+export function getBookIndexPDA(): PublicKey {
+  const [bookIndexPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("book_index")],
+    PROGRAM_ID,
+  );
+  return bookIndexPDA;
+}
+
+// Mirrors title_index_key: SHA-256 of the lowercased title and author, NUL-separated
+export function getTitleIndexPDA(title: string, author: string): PublicKey {
+  const key = createHash("sha256")
+    .update(`${title.toLowerCase()}\0${author.toLowerCase()}`)
+    .digest();
+  const [titleIndexPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("title_idx"), key],
+    PROGRAM_ID,
+  );
+  return titleIndexPDA;
+}
+
+// Mirrors author_index_key: SHA-256 of the lowercased primary author
+export function getAuthorIndexPDA(author: string): PublicKey {
+  const key = createHash("sha256").update(author.toLowerCase()).digest();
+  const [authorIndexPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("author_idx"), key],
+    PROGRAM_ID,
+  );
+  return authorIndexPDA;
+}
+
+export function getGenreIndexPDA(genre: number): PublicKey {
+  const [genreIndexPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("genre_idx"), Buffer.from([genre])],
+    PROGRAM_ID,
+  );
+  return genreIndexPDA;
+}
+
+export function getSeqIndexPDA(seq: bigint): PublicKey {
+  const seqBytes = Buffer.alloc(8);
+  seqBytes.writeBigUInt64LE(seq);
+  const [seqIndexPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("seq"), seqBytes],
+    PROGRAM_ID,
+  );
+  return seqIndexPDA;
+}
+
+// Genre variants in declaration order, matching the on-chain enum byte
+export const GENRES = [
+  "fiction",
+  "non-fiction",
+  "science",
+  "history",
+  "biography",
+  "poetry",
+  "children",
+  "reference",
+  "other",
+];
+export const GENRE_OTHER = GENRES.indexOf("other");
+
+// Known genres map to their variant; anything else is Other with the name as its label
+export function parseGenre(name: string): { genre: number; label: string } {
+  const genre = GENRES.indexOf(name.toLowerCase());
+  if (genre === -1) {
+    return { genre: GENRE_OTHER, label: name };
+  }
+  return { genre, label: "" };
+}
+
+// Borsh String: u32 little-endian byte length, then the UTF-8 bytes
+export function encodeString(value: string): Buffer {
+  const bytes = Buffer.from(value, "utf8");
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(bytes.length);
+  return Buffer.concat([length, bytes]);
+}
+
+// Walk the DoLState layout up to next_book_seq, past its variable-length fields
+export function readNextBookSeq(data: Buffer): bigint {
+  let offset = 8 + 32; // Discriminator, super_admin
+  const skipPubkeyVec = () => {
+    offset += 4 + data.readUInt32LE(offset) * 32;
+  };
+  const skipPubkeyOption = () => {
+    offset += data[offset] === 1 ? 1 + 32 : 1;
+  };
+
+  skipPubkeyVec(); // admins
+  skipPubkeyVec(); // moderators
+  skipPubkeyVec(); // curators
+  offset += 8 + 1 + 1 + 1; // book_count, version, flags, bump
+  skipPubkeyOption(); // pending_super_admin
+  offset += 8 + 8 + 1 + 8; // Transfer timestamps, recovery threshold and start
+  skipPubkeyVec(); // emergency_recovery_votes
+  skipPubkeyOption(); // emergency_recovery_new_admin
+  offset += 8 + 2 + 8 + 1; // Rate limiting fields, allow_rating_update
+  offset += 8 + 8 + 8 + 8; // Pause toggle fields, book_visibility_delay
+  offset += 8 + 8 + 8 + 8; // membership_number, card fee, recovery timelock, renounce
+  offset += 8 + 8 + 4 + 8; // max_books, upgrade fee, permissions, min card age
+  return data.readBigUInt64LE(offset);
+}
+
 export function generateBookId(): Uint8Array {
   const uuid = randomUUID();
   const hex = uuid.replace(/-/g, "");
@@ -50,25 +178,26 @@ export function getInstructionDiscriminator(instructionName: string): Buffer {
 }
 
 export function validateIpfsHash(hash: string): boolean {
-  // Basic length and prefix validation
-  if (hash.length < 32 || !(hash.startsWith("Qm") || hash.startsWith("baf"))) {
-    return false;
-  }
-
-  // Validate character sets based on IPFS hash type
+  // Validate character sets and lengths based on IPFS hash type
   if (hash.startsWith("Qm")) {
-    // CIDv0 - Base58 validation (Bitcoin alphabet without 0, O, I, l)
+    // CIDv0 - Base58 sha2-256 multihash (Bitcoin alphabet without 0, O, I, l)
     const BASE58_CHARS =
       "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
-    return hash.split("").every((char) => BASE58_CHARS.includes(char));
-  } else if (hash.startsWith("baf")) {
-    // CIDv1 - Base32 validation (RFC 4648 lowercase)
+    return (
+      hash.length === 46 &&
+      hash.split("").every((char) => BASE58_CHARS.includes(char))
+    );
+  } else if (hash.startsWith("bafy") || hash.startsWith("bafk")) {
+    // CIDv1 - dag-pb or raw codec, Base32 validation (RFC 4648 lowercase)
     const BASE32_CHARS = "abcdefghijklmnopqrstuvwxyz234567";
-    // Skip the first 3 characters ("baf") and validate the rest
-    return hash
-      .slice(3)
-      .split("")
-      .every((char) => BASE32_CHARS.includes(char));
+    // Skip the multibase prefix ("b") and validate the rest
+    return (
+      hash.length >= 59 &&
+      hash
+        .slice(1)
+        .split("")
+        .every((char) => BASE32_CHARS.includes(char))
+    );
   }
 
   return false;
@@ -82,6 +211,9 @@ export function showUsage(): void {
   console.log(
     "  add-book --keypair <path> --title <title> --author <author> --ipfs <hash> --genre <genre> - Add book (admin/curator)",
   );
+  console.log(
+    `    <genre> is one of ${GENRES.join(", ")}; any other name is stored as an Other genre label`,
+  );
   console.log(
     "  update-book --keypair <path> --book-id <id> [--title <title>] [--author <author>] [--ipfs <hash>] [--genre <genre>] - Update book (admin/curator)",
   );
@@ -121,11 +253,14 @@ export function showUsage(): void {
     "  initiate-super-admin-transfer --keypair <path> --new-super-admin <pubkey> - Start transfer (super admin only)",
   );
   console.log(
-    "  confirm-super-admin-transfer --keypair <path> - Complete transfer after timelock (super admin only)",
+    "  confirm-super-admin-transfer --keypair <path> - Complete transfer after timelock (pending super admin only)",
   );
   console.log(
     "  cancel-super-admin-transfer --keypair <path> - Cancel pending transfer (super admin only)",
   );
+  console.log(
+    "  renounce-super-admin --keypair <path> - IRREVERSIBLY give up super admin; run twice within 10 minutes (super admin only)",
+  );
   console.log("");
   console.log("🚨 Emergency Recovery (Multi-admin signatures):");
   console.log(
@@ -134,6 +269,9 @@ export function showUsage(): void {
   console.log(
     "  vote-emergency-recovery --keypair <path> - Vote for recovery (admin only)",
   );
+  console.log(
+    "  execute-emergency-recovery --keypair <path> - Execute recovery after its timelock (admin only)",
+  );
   console.log("");
   console.log("🚨 Emergency Controls:");
   console.log(